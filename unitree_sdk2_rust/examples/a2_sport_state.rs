@@ -0,0 +1,61 @@
+//! Example: Print the A2 sport-mode state.
+//!
+//! Queries `GETSTATE` once, then prints position, velocity, FSM state and
+//! progress from every `rt/sportmodestate` message.
+
+use std::time::Duration;
+use unitree_sdk2_rust::channel::{ChannelFactory, ChannelSubscriber};
+use unitree_sdk2_rust::idl::go2::SportModeState;
+use unitree_sdk2_rust::robot::a2::sport::{FsmState, SportClient};
+use unitree_sdk2_rust::robot::go2::sport::sport_api::TOPIC_SPORT_MODE_STATE;
+
+fn main() {
+    env_logger::init();
+
+    let interface = std::env::args().nth(1).unwrap_or_else(|| "eth0".to_string());
+    ChannelFactory::init(0, &interface);
+
+    let mut client = SportClient::new();
+    client.set_timeout(5.0);
+    client.init();
+
+    match client.get_state() {
+        Ok(state) => println!(
+            "fsm_id: {:?} fsm_name: {:?} speed_level: {:?} auto_recovery_switch: {:?} process_state: {:?}",
+            state.fsm_id,
+            state.fsm_name,
+            state.speed_level,
+            state.auto_recovery_switch,
+            state.process_state
+        ),
+        Err(code) => println!("get_state error: {code}"),
+    }
+
+    let mut subscriber: ChannelSubscriber<SportModeState> = ChannelFactory::instance()
+        .lock()
+        .unwrap()
+        .create_subscriber(TOPIC_SPORT_MODE_STATE);
+
+    subscriber
+        .init_channel(|state: &SportModeState| {
+            let mode = match FsmState::try_from(state.mode as i32) {
+                Ok(fsm) => fsm.name().to_string(),
+                Err(id) => format!("UNKNOWN({id})"),
+            };
+            println!(
+                "Position: {}, {}, {}",
+                state.position[0], state.position[1], state.position[2]
+            );
+            println!(
+                "Velocity: {}, {}, {}",
+                state.velocity[0], state.velocity[1], state.velocity[2]
+            );
+            println!("Mode: {mode}");
+            println!("Progress: {}", state.progress);
+        })
+        .expect("Subscriber init failed");
+
+    std::thread::sleep(Duration::from_secs(10));
+
+    subscriber.close_channel();
+}
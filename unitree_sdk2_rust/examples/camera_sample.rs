@@ -0,0 +1,64 @@
+//! Example: Save JPEG samples from a Go2 or B2 camera.
+//!
+//! Usage: `camera_sample [go2|b2-front|b2-back]` (default `go2`). Every three
+//! seconds a frame is fetched through the `CameraSource` trait and written
+//! to `<camera>_<n>.jpg`.
+
+use std::time::Duration;
+use unitree_sdk2_rust::camera::{is_jpeg, CameraSource};
+use unitree_sdk2_rust::channel::ChannelFactory;
+use unitree_sdk2_rust::robot::b2::back_video::BackVideoClient;
+use unitree_sdk2_rust::robot::b2::front_video::FrontVideoClient;
+use unitree_sdk2_rust::robot::go2::video::VideoClient;
+
+fn open_camera(name: &str) -> Option<Box<dyn CameraSource>> {
+    let camera: Box<dyn CameraSource> = match name {
+        "go2" => {
+            let mut client = VideoClient::new();
+            client.set_timeout(1.0);
+            client.init();
+            Box::new(client)
+        }
+        "b2-front" => {
+            let mut client = FrontVideoClient::new();
+            client.set_timeout(1.0);
+            client.init();
+            Box::new(client)
+        }
+        "b2-back" => {
+            let mut client = BackVideoClient::new();
+            client.set_timeout(1.0);
+            client.init();
+            Box::new(client)
+        }
+        _ => return None,
+    };
+    Some(camera)
+}
+
+fn main() {
+    env_logger::init();
+
+    ChannelFactory::init(0, "eth0");
+
+    let name = std::env::args().nth(1).unwrap_or_else(|| "go2".to_string());
+    let Some(camera) = open_camera(&name) else {
+        eprintln!("Unknown camera '{name}', expected go2, b2-front or b2-back");
+        std::process::exit(1);
+    };
+
+    for n in 0..5 {
+        match camera.image_sample() {
+            Ok(image) if is_jpeg(&image) => {
+                let path = format!("{}_{n}.jpg", camera.source_name());
+                match std::fs::write(&path, &image) {
+                    Ok(()) => println!("Image saved as {path} ({} bytes)", image.len()),
+                    Err(e) => eprintln!("Failed to save {path}: {e}"),
+                }
+            }
+            Ok(image) => println!("Sample is not a JPEG ({} bytes), skipped", image.len()),
+            Err(code) => println!("image_sample error: {code}"),
+        }
+        std::thread::sleep(Duration::from_secs(3));
+    }
+}
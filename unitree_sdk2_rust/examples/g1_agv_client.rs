@@ -0,0 +1,39 @@
+//! Example: Drive the G1 wheeled base using AgvClient.
+//!
+//! Sweeps the base velocity and column height along a sine wave while a
+//! `VelocityHold` keeps re-sending the current velocity every 50 ms.
+
+use std::f32::consts::PI;
+use std::sync::Arc;
+use std::time::Duration;
+use unitree_sdk2_rust::channel::ChannelFactory;
+use unitree_sdk2_rust::robot::g1::agv::{AgvClient, VelocityHold};
+
+fn main() {
+    env_logger::init();
+
+    ChannelFactory::init(0, "eth0");
+
+    let mut client = AgvClient::new();
+    client.set_timeout(3.0);
+    client.init();
+    let client = Arc::new(client);
+
+    let hold = VelocityHold::start(Arc::clone(&client), Duration::from_millis(50));
+
+    let cycle_period = 40.0_f32;
+    for cycle_count in 0..80 {
+        let phase = (cycle_count % cycle_period as i32) as f32 / cycle_period * 2.0 * PI;
+        let vx = 0.3 * phase.sin();
+        let vyaw = 0.3 * phase.sin();
+        hold.set(vx, 0.0, vyaw);
+        println!("Hold velocity vx:{vx} vyaw:{vyaw}");
+
+        let height = phase.sin();
+        println!("Call height_adjust height:{height} ret:{}", client.height_adjust(height));
+        std::thread::sleep(Duration::from_millis(100));
+    }
+
+    drop(hold);
+    println!("Base stopped.");
+}
@@ -0,0 +1,106 @@
+//! Example: Swing the G1 ankles in PR mode, then in AB mode.
+//!
+//! Port of `g1_ankle_swing_example.cpp`: the robot is first brought to the
+//! zero posture, then the ankles swing in pitch/roll for three seconds and
+//! afterwards drive the A/B motors directly. `HgLowCmdPublisher` fills in
+//! `mode_pr`, `mode_machine` and the CRC. Hang the robot before running.
+
+use std::f32::consts::PI;
+use std::time::{Duration, Instant};
+use unitree_sdk2_rust::channel::ChannelFactory;
+use unitree_sdk2_rust::control::{AnkleMode, HgLowCmdPublisher, ParallelJoint};
+use unitree_sdk2_rust::idl::hg::LowCmd;
+use unitree_sdk2_rust::robot::b2::motion_switcher::{MotionSwitcherClient, ReleasedMode};
+
+const G1_NUM_MOTOR: usize = 29;
+const KP: [f32; G1_NUM_MOTOR] = [
+    60.0, 60.0, 60.0, 100.0, 40.0, 40.0, // left leg
+    60.0, 60.0, 60.0, 100.0, 40.0, 40.0, // right leg
+    60.0, 40.0, 40.0, // waist
+    40.0, 40.0, 40.0, 40.0, 40.0, 40.0, 40.0, // left arm
+    40.0, 40.0, 40.0, 40.0, 40.0, 40.0, 40.0, // right arm
+];
+const KD: [f32; G1_NUM_MOTOR] = [
+    1.0, 1.0, 1.0, 2.0, 1.0, 1.0, 1.0, 1.0, 1.0, 2.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0,
+    1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0,
+];
+const DURATION: f32 = 3.0;
+const CONTROL_DT: Duration = Duration::from_millis(2);
+
+fn main() {
+    env_logger::init();
+
+    ChannelFactory::init(0, "eth0");
+
+    let mut msc = MotionSwitcherClient::new();
+    msc.set_timeout(5.0);
+    msc.init();
+    let _released = ReleasedMode::acquire(msc).expect("Failed to release motion mode");
+
+    let mut publisher =
+        HgLowCmdPublisher::new("rt/lowcmd", "rt/lowstate", AnkleMode::Pr).expect("Publisher init failed");
+
+    let start = Instant::now();
+    let initial = loop {
+        if let Some(state) = publisher.latest_state() {
+            break state;
+        }
+        if start.elapsed() > Duration::from_secs(5) {
+            println!("No LowState received, exiting");
+            return;
+        }
+        std::thread::sleep(CONTROL_DT);
+    };
+    println!("G1 type: {}", initial.mode_machine);
+
+    let mut cmd = LowCmd::default();
+    let left = ParallelJoint::LeftAnkle;
+    let right = ParallelJoint::RightAnkle;
+    let start = Instant::now();
+    loop {
+        let time = start.elapsed().as_secs_f32();
+        for i in 0..G1_NUM_MOTOR {
+            let m = &mut cmd.motor_cmd[i];
+            m.mode = 1;
+            m.q = 0.0;
+            m.dq = 0.0;
+            m.tau = 0.0;
+            m.kp = KP[i];
+            m.kd = KD[i];
+        }
+
+        if time < DURATION {
+            // Stage 1: move from the initial pose to the zero posture.
+            let ratio = time / DURATION;
+            for (m, s) in cmd.motor_cmd.iter_mut().zip(&initial.motor_state).take(G1_NUM_MOTOR) {
+                m.q = (1.0 - ratio) * s.q;
+            }
+        } else if time < DURATION * 2.0 {
+            publisher.set_mode(AnkleMode::Pr);
+            let t = time - DURATION;
+            let (max_p, max_r) = (30f32.to_radians(), 10f32.to_radians());
+            let s = (2.0 * PI * t).sin();
+            cmd.motor_cmd[left.pitch_index()].q = max_p * s;
+            cmd.motor_cmd[left.roll_index()].q = max_r * s;
+            cmd.motor_cmd[right.pitch_index()].q = max_p * s;
+            cmd.motor_cmd[right.roll_index()].q = -max_r * s;
+        } else if time < DURATION * 4.0 {
+            publisher.set_mode(AnkleMode::Ab);
+            let t = time - DURATION * 2.0;
+            let (max_a, max_b) = (30f32.to_radians(), 10f32.to_radians());
+            cmd.motor_cmd[left.a_index()].q = max_a * (PI * t).sin();
+            cmd.motor_cmd[left.b_index()].q = max_b * (PI * t + PI).sin();
+            cmd.motor_cmd[right.a_index()].q = -max_a * (PI * t).sin();
+            cmd.motor_cmd[right.b_index()].q = -max_b * (PI * t + PI).sin();
+        } else {
+            break;
+        }
+
+        if let Err(e) = publisher.write(&mut cmd) {
+            println!("Failed to write LowCmd: {e}");
+            return;
+        }
+        std::thread::sleep(CONTROL_DT);
+    }
+    println!("Done");
+}
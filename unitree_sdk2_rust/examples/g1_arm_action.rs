@@ -0,0 +1,41 @@
+//! Example: Trigger G1 arm gestures using ArmActionClient.
+//!
+//! Usage: `g1_arm_action list | <action_id> | name <custom_action> | stop`
+
+use unitree_sdk2_rust::channel::ChannelFactory;
+use unitree_sdk2_rust::robot::g1::arm::{ArmAction, ArmActionClient};
+
+fn main() {
+    env_logger::init();
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.is_empty() {
+        println!("Usage: g1_arm_action list | <action_id> | name <custom_action> | stop");
+        for action in ArmAction::ALL {
+            println!("  {:>3}  {}", action.id(), action.name());
+        }
+        return;
+    }
+
+    ChannelFactory::init(0, "eth0");
+
+    let mut client = ArmActionClient::new();
+    client.init();
+    client.set_timeout(10.0);
+
+    match args[0].as_str() {
+        "list" => match client.get_action_list() {
+            Ok(list) => println!("Available actions: {list:#}"),
+            Err(code) => println!("Failed to get action list, error code: {code}"),
+        },
+        "name" => {
+            let name = args.get(1).map(String::as_str).unwrap_or_default();
+            println!("execute_custom_action({name}): ret={}", client.execute_custom_action(name));
+        }
+        "stop" => println!("stop_custom_action: ret={}", client.stop_custom_action()),
+        id => match id.parse::<i32>() {
+            Ok(id) => println!("execute_action({id}): ret={}", client.execute_action(id)),
+            Err(_) => println!("Invalid action id: {id}"),
+        },
+    }
+}
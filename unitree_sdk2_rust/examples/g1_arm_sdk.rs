@@ -0,0 +1,54 @@
+//! Example: Raise and lower the G1 arms through `rt/arm_sdk` while the
+//! locomotion controller keeps the robot balanced.
+//!
+//! Usage: `g1_arm_sdk [5dof]` (default: 7-DoF arms)
+
+use std::f32::consts::FRAC_PI_2;
+use std::time::Duration;
+use unitree_sdk2_rust::channel::ChannelFactory;
+use unitree_sdk2_rust::control::{ArmJoint, ArmSdk, ArmSdkConfig, G1Arm5Joint, G1Arm7Joint};
+
+fn run<J: ArmJoint>(raised: &[f32]) {
+    let mut arms: ArmSdk<J> = ArmSdk::new(ArmSdkConfig::default()).expect("ArmSdk init failed");
+
+    // Give the state subscriber a moment to receive the current pose.
+    std::thread::sleep(Duration::from_millis(500));
+    if let Err(e) = arms.engage() {
+        println!("Cannot engage arms: {e}");
+        return;
+    }
+
+    let start = arms.setpoint().to_vec();
+    println!("Engaging arms from {start:.2?}");
+
+    arms.set_targets(raised);
+    if let Err(e) = arms.settle() {
+        println!("Failed to raise arms: {e}");
+        return;
+    }
+    println!("Arms raised, lowering");
+
+    arms.set_targets(&start);
+    if let Err(e) = arms.settle() {
+        println!("Failed to lower arms: {e}");
+        return;
+    }
+
+    // Dropping `arms` ramps the weight back to 0.
+    println!("Releasing arms");
+}
+
+fn main() {
+    env_logger::init();
+
+    ChannelFactory::init(0, "eth0");
+
+    if std::env::args().nth(1).as_deref() == Some("5dof") {
+        run::<G1Arm5Joint>(&[0.0, FRAC_PI_2, 0.0, FRAC_PI_2, 0.0, 0.0, -FRAC_PI_2, 0.0, FRAC_PI_2, 0.0]);
+    } else {
+        run::<G1Arm7Joint>(&[
+            0.0, FRAC_PI_2, 0.0, FRAC_PI_2, 0.0, 0.0, 0.0,
+            0.0, -FRAC_PI_2, 0.0, FRAC_PI_2, 0.0, 0.0, 0.0,
+        ]);
+    }
+}
@@ -0,0 +1,51 @@
+//! Example: Speech, playback and LED control using the G1 AudioClient.
+//!
+//! Usage: `g1_audio_client [file.wav]`. The WAV file is converted to 16 kHz
+//! mono 16-bit PCM before playback.
+
+use std::time::Duration;
+use unitree_sdk2_rust::audio::Wav;
+use unitree_sdk2_rust::channel::ChannelFactory;
+use unitree_sdk2_rust::robot::g1::audio::{subscribe_asr, AudioClient};
+
+fn main() {
+    env_logger::init();
+
+    ChannelFactory::init(0, "eth0");
+
+    let mut client = AudioClient::new();
+    client.init();
+    client.set_timeout(10.0);
+
+    let _asr = subscribe_asr(|text| println!("Topic:\"rt/audio_msg\" recv: {text}"))
+        .expect("ASR subscriber init failed");
+
+    match client.get_volume() {
+        Ok(volume) => println!("get_volume: {volume}"),
+        Err(code) => println!("get_volume error: {code}"),
+    }
+    println!("set_volume(100): ret={}", client.set_volume(100));
+
+    let ret = client.tts_maker(
+        "Hello. I'm a robot from Unitree Robotics. The example has started successfully.",
+        1,
+    );
+    println!("tts_maker: ret={ret}");
+
+    if let Some(path) = std::env::args().nth(1) {
+        match Wav::read(&path).and_then(|wav| {
+            println!("wav file format: {:?}", wav.format);
+            wav.to_robot_pcm()
+        }) {
+            Ok(pcm) => {
+                println!("Playing {path}: {} bytes", pcm.len());
+                println!("play_pcm: ret={}", client.play_pcm("example", &pcm));
+            }
+            Err(e) => println!("Failed to load WAV file {path}: {e}"),
+        }
+    }
+
+    println!("led_control(0, 255, 0): ret={}", client.led_control(0, 255, 0));
+    std::thread::sleep(Duration::from_secs(1));
+    println!("led_control(0, 0, 255): ret={}", client.led_control(0, 0, 255));
+}
@@ -0,0 +1,47 @@
+//! Example: Open, close or release a G1 Dex3 hand and print its state.
+//!
+//! Usage: `g1_dex3 <left|right> <open|close|stop|print>`
+
+use std::time::Duration;
+use unitree_sdk2_rust::channel::ChannelFactory;
+use unitree_sdk2_rust::robot::g1::dex3::{Dex3Hand, HandSide};
+
+fn main() {
+    env_logger::init();
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let side = match args.first().map(String::as_str) {
+        Some("left") => HandSide::Left,
+        Some("right") => HandSide::Right,
+        _ => {
+            println!("Usage: g1_dex3 <left|right> <open|close|stop|print>");
+            return;
+        }
+    };
+
+    ChannelFactory::init(0, "eth0");
+
+    let mut hand = Dex3Hand::new(side, true).expect("Dex3 init failed");
+
+    let result = match args.get(1).map(String::as_str).unwrap_or("print") {
+        "open" => hand.open(1.5, 0.1),
+        "close" => hand.close(1.5, 0.1),
+        "stop" => hand.stop(),
+        _ => Ok(()),
+    };
+    if let Err(e) = result {
+        println!("Failed to send command: {e}");
+        return;
+    }
+
+    std::thread::sleep(Duration::from_secs(1));
+    match hand.normalized_positions() {
+        Some(q) => println!("{} hand joints (0 = min, 1 = max): {q:.2?}", side.name()),
+        None => println!("No state received on {}", side.state_topic(true)),
+    }
+    if let Some(pressures) = hand.pressures() {
+        for (pad, p) in pressures.iter().enumerate() {
+            println!("  pad {pad}: {:.1}", p.iter().sum::<f32>());
+        }
+    }
+}
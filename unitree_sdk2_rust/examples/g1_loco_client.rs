@@ -0,0 +1,30 @@
+//! Example: Control the G1 humanoid using LocoClient.
+//!
+//! Starts the locomotion controller, walks forward briefly, waves, and
+//! returns to a damped state.
+
+use std::time::Duration;
+use unitree_sdk2_rust::channel::ChannelFactory;
+use unitree_sdk2_rust::robot::g1::loco::LocoClient;
+
+fn main() {
+    env_logger::init();
+
+    ChannelFactory::init(0, "eth0");
+
+    let mut client = LocoClient::new();
+    client.set_timeout(10.0);
+    client.init();
+
+    match client.get_fsm_id() {
+        Ok(id) => println!("Current fsm_id: {id}"),
+        Err(code) => println!("get_fsm_id error: {code}"),
+    }
+
+    println!("Sending start command: ret={}", client.start());
+    println!("Sending move_cmd (0.3, 0.0, 0.0): ret={}", client.move_cmd(0.3, 0.0, 0.0));
+    std::thread::sleep(Duration::from_secs(1));
+    println!("Sending stop_move command: ret={}", client.stop_move());
+    println!("Sending wave_hand command: ret={}", client.wave_hand(false));
+    println!("Sending damp command: ret={}", client.damp());
+}
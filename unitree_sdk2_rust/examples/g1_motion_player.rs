@@ -0,0 +1,83 @@
+//! Example: Play a keyframe motion sequence on the G1 through `rt/lowcmd`.
+//!
+//! Usage: `g1_motion_player <sequence.json | motion.seq> [loop]`
+//!
+//! `.seq` files are read as Choreonoid body motions driving the 14 arm
+//! joints, like the C++ dual-arm example. The other joints hold their
+//! starting pose with the example's gearbox gains. While playing, type `p` to pause,
+//! `r` to resume and `a` to abort, each followed by Enter.
+
+use std::io::BufRead;
+use unitree_sdk2_rust::channel::ChannelFactory;
+use unitree_sdk2_rust::control::RunnerConfig;
+use unitree_sdk2_rust::motion::{HoldGains, MotionPlayer, MotionSequence, PlayerConfig};
+use unitree_sdk2_rust::robot::b2::motion_switcher::{MotionSwitcherClient, ReleasedMode};
+use unitree_sdk2_rust::safety::SafetyMonitor;
+
+/// HG motor index of the left shoulder pitch joint, the first arm joint.
+const LEFT_SHOULDER_PITCH: usize = 15;
+
+fn main() {
+    env_logger::init();
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let Some(path) = args.first() else {
+        println!("Usage: g1_motion_player <sequence.json | motion.seq> [loop]");
+        return;
+    };
+
+    let sequence = if path.ends_with(".seq") {
+        MotionSequence::read_choreonoid_seq(path, LEFT_SHOULDER_PITCH, 3.0, 40.0, 1.0)
+    } else {
+        MotionSequence::read(path)
+    };
+    let sequence = match sequence {
+        Ok(sequence) => sequence,
+        Err(e) => {
+            println!("Failed to load {path}: {e}");
+            return;
+        }
+    };
+    println!(
+        "Loaded {} keyframes, {:.2} s, joints {:?}",
+        sequence.len(),
+        sequence.duration(),
+        sequence.joints()
+    );
+
+    ChannelFactory::init(0, "eth0");
+
+    let mut msc = MotionSwitcherClient::new();
+    msc.set_timeout(5.0);
+    msc.init();
+    let _released = ReleasedMode::acquire(msc).expect("Failed to release motion mode");
+
+    let config = PlayerConfig {
+        looping: args.get(1).is_some_and(|a| a == "loop"),
+        ..Default::default()
+    };
+    let player = MotionPlayer::new(sequence, config, HoldGains::g1_gearbox());
+    let handle = player.handle();
+    let mut runner = player
+        .into_runner(RunnerConfig::default())
+        .expect("Runner init failed")
+        .with_monitor(SafetyMonitor::default());
+
+    std::thread::spawn(move || {
+        for line in std::io::stdin().lock().lines() {
+            let Ok(line) = line else { break };
+            let ok = match line.trim() {
+                "p" => handle.pause(),
+                "r" => handle.resume(),
+                "a" => handle.abort(),
+                _ => continue,
+            };
+            println!("{:?} (accepted: {ok})", handle.state());
+        }
+    });
+
+    match runner.run() {
+        Ok(reason) => println!("Playback stopped: {reason:?}"),
+        Err(e) => println!("Playback error: {e}"),
+    }
+}
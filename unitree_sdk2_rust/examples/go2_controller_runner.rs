@@ -0,0 +1,78 @@
+//! Example: Stand the Go2 up with a `Controller` driven by `ControlRunner`.
+//!
+//! The controller interpolates every leg joint from its position at start
+//! to a standing pose over two seconds and then holds it. The runner stops
+//! after ten seconds, or earlier on stale state or a safety fault, and
+//! damps the motors on the way out.
+
+use std::time::Duration;
+use unitree_sdk2_rust::channel::ChannelFactory;
+use unitree_sdk2_rust::control::state_machine::{servo_low_cmd, GO2_NUM_JOINTS};
+use unitree_sdk2_rust::control::{ControlRunner, Controller, RunnerConfig};
+use unitree_sdk2_rust::idl::go2::{LowCmd, LowState};
+use unitree_sdk2_rust::robot::b2::motion_switcher::{MotionSwitcherClient, ReleasedMode};
+use unitree_sdk2_rust::safety::SafetyMonitor;
+
+const STAND_POS: [f32; GO2_NUM_JOINTS] = [
+    0.0, 0.67, -1.3, 0.0, 0.67, -1.3, 0.0, 0.67, -1.3, 0.0, 0.67, -1.3,
+];
+const STAND_DURATION: f32 = 2.0;
+
+struct StandUp {
+    start_pos: [f32; GO2_NUM_JOINTS],
+    elapsed: f32,
+}
+
+impl Controller<LowState, LowCmd> for StandUp {
+    fn on_start(&mut self, state: &LowState) {
+        for (start, motor) in self.start_pos.iter_mut().zip(&state.motor_state) {
+            *start = motor.q;
+        }
+    }
+
+    fn compute(&mut self, _state: &LowState, cmd: &mut LowCmd, dt: f32) {
+        self.elapsed += dt;
+        let phase = (self.elapsed / STAND_DURATION).min(1.0);
+        for (i, motor) in cmd.motor_cmd.iter_mut().take(GO2_NUM_JOINTS).enumerate() {
+            motor.q = self.start_pos[i] + (STAND_POS[i] - self.start_pos[i]) * phase;
+            motor.dq = 0.0;
+            motor.kp = 60.0;
+            motor.kd = 5.0;
+            motor.tau = 0.0;
+        }
+    }
+
+    fn on_stop(&mut self) {
+        println!("Controller stopped after {:.2} s", self.elapsed);
+    }
+}
+
+fn main() {
+    env_logger::init();
+
+    ChannelFactory::init(0, "eth0");
+
+    let mut msc = MotionSwitcherClient::new();
+    msc.set_timeout(5.0);
+    msc.init();
+    let _released = ReleasedMode::acquire(msc).expect("Failed to release motion mode");
+
+    let controller = StandUp {
+        start_pos: [0.0; GO2_NUM_JOINTS],
+        elapsed: 0.0,
+    };
+    let mut runner = ControlRunner::new(controller, servo_low_cmd(), RunnerConfig::default())
+        .expect("Runner init failed")
+        .with_monitor(SafetyMonitor::default());
+
+    let stop = runner.stop_handle();
+    std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_secs(10));
+        stop.stop();
+    });
+
+    match runner.run() {
+        Ok(reason) => println!("Runner stopped: {reason:?}"),
+        Err(e) => println!("Runner error: {e}"),
+    }
+}
@@ -2,10 +2,13 @@
 //!
 //! Demonstrates how to use ChannelPublisher<LowCmd> and
 //! ChannelSubscriber<LowState> to send motor commands and receive robot state.
+//! The built-in sport controller is released first and restored on exit.
 
 use std::time::Duration;
 use unitree_sdk2_rust::channel::{ChannelFactory, ChannelPublisher, ChannelSubscriber};
+use unitree_sdk2_rust::idl::crc::Crc;
 use unitree_sdk2_rust::idl::go2::{LowCmd, LowState};
+use unitree_sdk2_rust::robot::b2::motion_switcher::{MotionSwitcherClient, ReleasedMode};
 
 fn main() {
     env_logger::init();
@@ -13,6 +16,12 @@ fn main() {
     // Initialize the channel factory.
     ChannelFactory::init(0, "eth0");
 
+    // Release the sport controller so it does not fight our low-level commands.
+    let mut msc = MotionSwitcherClient::new();
+    msc.set_timeout(5.0);
+    msc.init();
+    let _released = ReleasedMode::acquire(msc).expect("Failed to release motion mode");
+
     let factory = ChannelFactory::instance().lock().unwrap();
 
     // Create publisher and subscriber.
@@ -35,7 +44,8 @@ fn main() {
         .expect("Subscriber init failed");
 
     // Publish a default LowCmd.
-    let cmd = LowCmd::default();
+    let mut cmd = LowCmd::default();
+    cmd.stamp_crc();
     match publisher.write(&cmd) {
         Ok(true) => println!("LowCmd published successfully"),
         Ok(false) => println!("LowCmd publish returned false"),
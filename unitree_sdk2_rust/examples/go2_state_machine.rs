@@ -0,0 +1,65 @@
+//! Example: Stand the Go2 up with the low-level state machine.
+//!
+//! Ramps into STAND, switches to CTRL once the stand has converged, holds
+//! the stand pose with the controller gains for a few seconds, then damps.
+//! The built-in sport controller is released first and restored on exit.
+
+use std::time::{Duration, Instant};
+use unitree_sdk2_rust::channel::ChannelFactory;
+use unitree_sdk2_rust::control::state_machine::GO2_NUM_JOINTS;
+use unitree_sdk2_rust::control::{Controller, Phase, StateMachine, StateMachineConfig};
+use unitree_sdk2_rust::idl::go2::{LowCmd, LowState};
+use unitree_sdk2_rust::robot::b2::motion_switcher::{MotionSwitcherClient, ReleasedMode};
+
+const DT: Duration = Duration::from_millis(10);
+
+/// Holds the stand pose with its own gains, like the C++
+/// `ExampleUserController`.
+struct HoldPose {
+    pose: [f32; GO2_NUM_JOINTS],
+}
+
+impl Controller<LowState, LowCmd> for HoldPose {
+    fn compute(&mut self, _state: &LowState, cmd: &mut LowCmd, _dt: f32) {
+        for (motor, q) in cmd.motor_cmd.iter_mut().zip(self.pose) {
+            motor.q = q;
+            motor.kp = 40.0;
+            motor.kd = 1.0;
+        }
+    }
+}
+
+fn main() {
+    env_logger::init();
+
+    ChannelFactory::init(0, "eth0");
+
+    let mut msc = MotionSwitcherClient::new();
+    msc.set_timeout(5.0);
+    msc.init();
+    let _released = ReleasedMode::acquire(msc).expect("Failed to release motion mode");
+
+    let config = StateMachineConfig::default();
+    let controller = HoldPose { pose: config.stand_pos };
+    let mut machine = StateMachine::new(controller, config).expect("State machine init failed");
+
+    machine.stand();
+    let start = Instant::now();
+    let mut ctrl_since = None;
+    while start.elapsed() < Duration::from_secs(15) {
+        if machine.phase() == Phase::Stand && machine.ctrl() {
+            ctrl_since = Some(Instant::now());
+        }
+        if ctrl_since.is_some_and(|t| t.elapsed() > Duration::from_secs(5)) {
+            break;
+        }
+        if let Err(e) = machine.step() {
+            eprintln!("step failed: {e}");
+        }
+        std::thread::sleep(DT);
+    }
+
+    println!("Final phase: {:?}, pd_ratio: {:.2}", machine.phase(), machine.pd_ratio());
+    machine.stop();
+    machine.step().expect("Failed to send damping command");
+}
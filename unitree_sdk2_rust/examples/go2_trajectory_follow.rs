@@ -0,0 +1,45 @@
+//! Example: Follow a sinusoidal path with the Go2 SportClient.
+//!
+//! Port of `go2_trajectory_follow.cpp`: every control tick a fresh set of
+//! path points is sampled ahead of the current time and sent to the robot.
+
+use std::f32::consts::PI;
+use std::time::Duration;
+use unitree_sdk2_rust::channel::ChannelFactory;
+use unitree_sdk2_rust::idl::go2::PathPoint;
+use unitree_sdk2_rust::robot::go2::sport::{sample_path, SportClient};
+
+fn main() {
+    env_logger::init();
+
+    ChannelFactory::init(0, "eth0");
+
+    let mut client = SportClient::new(false);
+    client.set_timeout(10.0);
+    client.init();
+
+    let dt = 0.002_f32;
+    let vx = 0.3_f32;
+    let mut count = 0.0_f32;
+
+    for c in 1..=500 {
+        count += dt;
+        let path = sample_path(count, |var| PathPoint {
+            t_from_start: 0.0,
+            x: vx * var,
+            y: 0.6 * (PI * vx * var).sin(),
+            yaw: 2.0 * 0.6 * vx * PI * (PI * vx * var).cos(),
+            vx,
+            vy: PI * vx * (0.6 * (PI * vx * var).cos()),
+            vyaw: -PI * vx * 2.0 * 0.6 * vx * PI * (PI * vx * var).sin(),
+        });
+
+        let ret = client.trajectory_follow(&path);
+        if ret != 0 {
+            println!("Call trajectory_follow: {ret}");
+        }
+        println!("{c}");
+
+        std::thread::sleep(Duration::from_secs_f32(dt));
+    }
+}
@@ -0,0 +1,63 @@
+//! Example: Go2W wheel velocity control in low-level mode.
+//!
+//! Holds the legs in the stand pose of `go2w_stand_example` and spins the
+//! wheels forward, backward, then stops them, ramping the speed with a
+//! bounded acceleration. The sport controller is released first and
+//! restored on exit.
+
+use std::time::Duration;
+use unitree_sdk2_rust::channel::{ChannelFactory, ChannelPublisher};
+use unitree_sdk2_rust::idl::crc::Crc;
+use unitree_sdk2_rust::idl::go2::LowCmd;
+use unitree_sdk2_rust::robot::b2::motion_switcher::{MotionSwitcherClient, ReleasedMode};
+use unitree_sdk2_rust::robot::wheeled::{WheelRamp, WheeledJoint, WheeledModel};
+
+const LEG_KP: f32 = 70.0;
+const LEG_KD: f32 = 5.0;
+const STAND_POSE: [f32; 12] = [0.0, 0.67, -1.3, 0.0, 0.67, -1.3, 0.0, 0.67, -1.3, 0.0, 0.67, -1.3];
+const DT: f32 = 0.002;
+
+fn main() {
+    env_logger::init();
+    ChannelFactory::init(0, "eth0");
+
+    let mut msc = MotionSwitcherClient::new();
+    msc.set_timeout(5.0);
+    msc.init();
+    let _released = ReleasedMode::acquire(msc).expect("Failed to release motion mode");
+
+    let factory = ChannelFactory::instance().lock().unwrap();
+    let mut publisher: ChannelPublisher<LowCmd> = factory.create_publisher("rt/lowcmd");
+    drop(factory);
+    publisher.init_channel().expect("Publisher init failed");
+
+    let mut cmd = LowCmd {
+        head: [0xFE, 0xEF],
+        level_flag: 0xFF,
+        ..Default::default()
+    };
+    for joint in WheeledJoint::ALL.into_iter().filter(|j| !j.is_wheel()) {
+        let info = joint.info(WheeledModel::Go2W);
+        let m = &mut cmd.motor_cmd[joint.index()];
+        m.mode = 0x01;
+        m.q = info.clamp(STAND_POSE[joint.index()]);
+        m.kp = LEG_KP;
+        m.kd = LEG_KD;
+    }
+
+    let mut ramp = WheelRamp::new(6.0, WheeledModel::Go2W.wheel_kd());
+    for (label, speed) in [("forward", 3.0), ("backward", -3.0), ("stop", 0.0)] {
+        println!("Wheels {label} at {speed} rad/s");
+        ramp.set_target([speed; 4]);
+        for _ in 0..(2.0 / DT) as usize {
+            ramp.step(&mut cmd, DT);
+            cmd.stamp_crc();
+            if let Err(e) = publisher.write(&cmd) {
+                println!("LowCmd publish error: {e}");
+                return;
+            }
+            std::thread::sleep(Duration::from_secs_f32(DT));
+        }
+    }
+    println!("Example complete.");
+}
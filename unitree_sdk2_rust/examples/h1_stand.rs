@@ -0,0 +1,101 @@
+//! Example: Bring H1 or H1-2 to its standing pose in low-level mode.
+//!
+//! H1 (default) uses the `unitree_go` IDL and the weak/strong motor gains of
+//! `humanoid.hpp`; pass `h1-2` for H1-2 on the `unitree_hg` IDL with the
+//! gearbox gains of `h1_27dof_example.cpp`. The pose is reached over three
+//! seconds from the pose at startup. Hang the robot before running.
+
+use std::time::{Duration, Instant};
+use unitree_sdk2_rust::channel::{ChannelFactory, ChannelPublisher, ChannelSubscriber};
+use unitree_sdk2_rust::control::{AnkleMode, HgLowCmdPublisher};
+use unitree_sdk2_rust::idl::crc::Crc;
+use unitree_sdk2_rust::idl::{go2, hg};
+use unitree_sdk2_rust::robot::b2::motion_switcher::{MotionSwitcherClient, ReleasedMode};
+use unitree_sdk2_rust::robot::h1::low_level::{set_h1_2_stand, set_h1_stand, H1_2_NUM_MOTOR, H1_NUM_MOTOR};
+
+const DURATION: f32 = 3.0;
+const CONTROL_DT: Duration = Duration::from_millis(2);
+const STATE_TIMEOUT: Duration = Duration::from_secs(5);
+
+fn main() {
+    env_logger::init();
+    let h1_2 = std::env::args().nth(1).as_deref() == Some("h1-2");
+
+    ChannelFactory::init(0, "eth0");
+
+    let mut msc = MotionSwitcherClient::new();
+    msc.set_timeout(5.0);
+    msc.init();
+    let _released = ReleasedMode::acquire(msc).expect("Failed to release motion mode");
+
+    if h1_2 {
+        stand_h1_2();
+    } else {
+        stand_h1();
+    }
+}
+
+fn stand_h1() {
+    let factory = ChannelFactory::instance().lock().unwrap();
+    let mut publisher: ChannelPublisher<go2::LowCmd> = factory.create_publisher("rt/lowcmd");
+    let mut subscriber: ChannelSubscriber<go2::LowState> = factory.create_subscriber("rt/lowstate");
+    drop(factory);
+    publisher.init_channel().expect("Publisher init failed");
+
+    let (tx, rx) = std::sync::mpsc::channel();
+    subscriber
+        .init_channel(move |state: &go2::LowState| {
+            let _ = tx.send(std::array::from_fn::<f32, H1_NUM_MOTOR, _>(|i| state.motor_state[i].q));
+        })
+        .expect("Subscriber init failed");
+    let Ok(initial) = rx.recv_timeout(STATE_TIMEOUT) else {
+        println!("No LowState received, exiting");
+        return;
+    };
+
+    let mut cmd = go2::LowCmd {
+        head: [0xFE, 0xEF],
+        level_flag: 0xFF,
+        ..Default::default()
+    };
+    let start = Instant::now();
+    while start.elapsed().as_secs_f32() < DURATION {
+        set_h1_stand(&mut cmd, &initial, start.elapsed().as_secs_f32() / DURATION);
+        cmd.stamp_crc();
+        if let Err(e) = publisher.write(&cmd) {
+            println!("LowCmd publish error: {e}");
+            return;
+        }
+        std::thread::sleep(CONTROL_DT);
+    }
+    println!("H1 standing.");
+}
+
+fn stand_h1_2() {
+    let mut publisher =
+        HgLowCmdPublisher::new("rt/lowcmd", "rt/lowstate", AnkleMode::Pr).expect("Publisher init failed");
+
+    let start = Instant::now();
+    let initial = loop {
+        if let Some(state) = publisher.latest_state() {
+            break std::array::from_fn::<f32, H1_2_NUM_MOTOR, _>(|i| state.motor_state[i].q);
+        }
+        if start.elapsed() > STATE_TIMEOUT {
+            println!("No LowState received, exiting");
+            return;
+        }
+        std::thread::sleep(CONTROL_DT);
+    };
+
+    let mut cmd = hg::LowCmd::default();
+    let start = Instant::now();
+    while start.elapsed().as_secs_f32() < DURATION {
+        set_h1_2_stand(&mut cmd, &initial, start.elapsed().as_secs_f32() / DURATION);
+        if let Err(e) = publisher.write(&mut cmd) {
+            println!("LowCmd publish error: {e}");
+            return;
+        }
+        std::thread::sleep(CONTROL_DT);
+    }
+    println!("H1-2 standing.");
+}
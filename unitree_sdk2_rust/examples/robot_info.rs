@@ -0,0 +1,37 @@
+//! Example: Identify the connected robot and print its joint table.
+
+use std::time::Duration;
+use unitree_sdk2_rust::channel::ChannelFactory;
+use unitree_sdk2_rust::robot::info::RobotInfo;
+
+fn main() {
+    env_logger::init();
+
+    ChannelFactory::init(0, "eth0");
+
+    let info = match RobotInfo::detect(Duration::from_secs(3)) {
+        Ok(info) => info,
+        Err(e) => {
+            println!("Detection failed: {e}");
+            return;
+        }
+    };
+
+    println!("IDL:      {:?}", info.idl);
+    match info.model() {
+        Some(model) => println!("Model:    {model} (unverified)"),
+        None => println!("Model:    unknown, candidates {:?}", info.candidates),
+    }
+    println!("Firmware: {}", info.firmware_version);
+    println!("Serial:   {}", info.serial_number.as_deref().unwrap_or("n/a"));
+    if let Some(mode_machine) = info.mode_machine {
+        println!("mode_machine: {mode_machine}");
+    }
+    let Some(joints) = info.joints() else {
+        return;
+    };
+    println!("Joints:");
+    for joint in joints {
+        println!("  {:>2}  {:<22} [{:>8.3}, {:>8.3}]", joint.index, joint.name, joint.min, joint.max);
+    }
+}
@@ -0,0 +1,4 @@
+//! Audio helpers for robot speakers.
+pub mod wav;
+
+pub use wav::{PcmChunk, PcmChunks, Wav, WavError, WavFormat};
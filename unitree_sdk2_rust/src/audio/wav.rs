@@ -0,0 +1,440 @@
+//! WAV parsing and PCM chunking for robot audio playback.
+//!
+//! The robot speakers accept 16 kHz, mono, 16-bit little-endian PCM. [`Wav`]
+//! parses RIFF/WAVE data in any common PCM layout and converts it to that
+//! format, downmixing and resampling as needed. [`PcmChunks`] then splits the
+//! PCM into sample-aligned chunks tagged with a stream id, ready to send via
+//! `AudioClient::play_stream` or as `AudioData` messages.
+
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use thiserror::Error;
+
+use crate::idl::go2::AudioData;
+
+const WAVE_FORMAT_PCM: u16 = 1;
+const WAVE_FORMAT_IEEE_FLOAT: u16 = 3;
+const WAVE_FORMAT_EXTENSIBLE: u16 = 0xFFFE;
+
+/// Error returned when reading or converting WAV data.
+#[derive(Debug, Error)]
+pub enum WavError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Not a RIFF/WAVE file")]
+    NotWave,
+    #[error("Missing {0} chunk")]
+    MissingChunk(&'static str),
+    #[error("Truncated {0} chunk")]
+    Truncated(&'static str),
+    #[error("Unsupported format: audio_format={audio_format} bits_per_sample={bits_per_sample}")]
+    Unsupported { audio_format: u16, bits_per_sample: u16 },
+}
+
+/// Sample layout of a WAV stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WavFormat {
+    pub audio_format: u16,
+    pub num_channels: u16,
+    pub sample_rate: u32,
+    pub bits_per_sample: u16,
+}
+
+impl WavFormat {
+    /// Format accepted by the robot: 16 kHz, mono, 16-bit PCM.
+    pub const ROBOT: WavFormat = WavFormat {
+        audio_format: WAVE_FORMAT_PCM,
+        num_channels: 1,
+        sample_rate: 16000,
+        bits_per_sample: 16,
+    };
+
+    /// Bytes per interleaved frame (one sample for every channel).
+    pub fn block_align(&self) -> usize {
+        self.num_channels as usize * (self.bits_per_sample as usize / 8)
+    }
+
+    /// Bytes per second of audio.
+    pub fn byte_rate(&self) -> usize {
+        self.sample_rate as usize * self.block_align()
+    }
+}
+
+/// A parsed WAV file: its format and raw sample data.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Wav {
+    pub format: WavFormat,
+    pub data: Vec<u8>,
+}
+
+fn le_u16(b: &[u8], at: usize) -> u16 {
+    u16::from_le_bytes([b[at], b[at + 1]])
+}
+
+fn le_u32(b: &[u8], at: usize) -> u32 {
+    u32::from_le_bytes([b[at], b[at + 1], b[at + 2], b[at + 3]])
+}
+
+impl Wav {
+    /// Read and parse a WAV file.
+    pub fn read(path: impl AsRef<Path>) -> Result<Self, WavError> {
+        Self::parse(&std::fs::read(path)?)
+    }
+
+    /// Parse RIFF/WAVE bytes. Unknown chunks (e.g. `LIST`, `JUNK`) are skipped.
+    pub fn parse(bytes: &[u8]) -> Result<Self, WavError> {
+        if bytes.len() < 12 || &bytes[0..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+            return Err(WavError::NotWave);
+        }
+
+        let mut format = None;
+        let mut pos = 12;
+        while pos + 8 <= bytes.len() {
+            let id = &bytes[pos..pos + 4];
+            let size = le_u32(bytes, pos + 4) as usize;
+            let body_start = pos + 8;
+            let body_end = body_start.saturating_add(size);
+
+            match id {
+                b"fmt " => {
+                    if size < 16 || body_end > bytes.len() {
+                        return Err(WavError::Truncated("fmt"));
+                    }
+                    let body = &bytes[body_start..body_end];
+                    let mut audio_format = le_u16(body, 0);
+                    if audio_format == WAVE_FORMAT_EXTENSIBLE && size >= 26 {
+                        // The first two bytes of the sub-format GUID carry the real format.
+                        audio_format = le_u16(body, 24);
+                    }
+                    format = Some(WavFormat {
+                        audio_format,
+                        num_channels: le_u16(body, 2),
+                        sample_rate: le_u32(body, 4),
+                        bits_per_sample: le_u16(body, 14),
+                    });
+                }
+                b"data" => {
+                    let format = format.ok_or(WavError::MissingChunk("fmt"))?;
+                    // Some writers leave the data size unset when streaming; take what is there.
+                    let end = body_end.min(bytes.len());
+                    let mut data = bytes[body_start..end].to_vec();
+                    let align = format.block_align().max(1);
+                    data.truncate(data.len() / align * align);
+                    return Ok(Self { format, data });
+                }
+                _ => {}
+            }
+
+            pos = body_end.saturating_add(size & 1);
+        }
+
+        Err(WavError::MissingChunk("data"))
+    }
+
+    /// Whether the data is already in the robot's playback format.
+    pub fn is_robot_format(&self) -> bool {
+        self.format == WavFormat::ROBOT
+    }
+
+    /// Decode the data into interleaved 16-bit samples.
+    pub fn samples(&self) -> Result<Vec<i16>, WavError> {
+        let f = &self.format;
+        let unsupported = || WavError::Unsupported {
+            audio_format: f.audio_format,
+            bits_per_sample: f.bits_per_sample,
+        };
+
+        let samples = match (f.audio_format, f.bits_per_sample) {
+            (WAVE_FORMAT_PCM, 8) => self.data.iter().map(|&b| ((b as i16) - 128) << 8).collect(),
+            (WAVE_FORMAT_PCM, 16) => self
+                .data
+                .chunks_exact(2)
+                .map(|c| i16::from_le_bytes([c[0], c[1]]))
+                .collect(),
+            (WAVE_FORMAT_PCM, 24) => self
+                .data
+                .chunks_exact(3)
+                .map(|c| i16::from_le_bytes([c[1], c[2]]))
+                .collect(),
+            (WAVE_FORMAT_PCM, 32) => self
+                .data
+                .chunks_exact(4)
+                .map(|c| i16::from_le_bytes([c[2], c[3]]))
+                .collect(),
+            (WAVE_FORMAT_IEEE_FLOAT, 32) => self
+                .data
+                .chunks_exact(4)
+                .map(|c| {
+                    let v = f32::from_le_bytes([c[0], c[1], c[2], c[3]]);
+                    (v.clamp(-1.0, 1.0) * i16::MAX as f32) as i16
+                })
+                .collect(),
+            _ => return Err(unsupported()),
+        };
+        Ok(samples)
+    }
+
+    /// Convert to 16 kHz mono 16-bit little-endian PCM bytes.
+    pub fn to_robot_pcm(&self) -> Result<Vec<u8>, WavError> {
+        if self.is_robot_format() {
+            return Ok(self.data.clone());
+        }
+        let mono = downmix(&self.samples()?, self.format.num_channels);
+        let resampled = resample(&mono, self.format.sample_rate, WavFormat::ROBOT.sample_rate);
+        Ok(resampled.iter().flat_map(|s| s.to_le_bytes()).collect())
+    }
+}
+
+/// Average interleaved channels into a single channel.
+pub fn downmix(samples: &[i16], num_channels: u16) -> Vec<i16> {
+    let channels = num_channels.max(1) as usize;
+    if channels == 1 {
+        return samples.to_vec();
+    }
+    samples
+        .chunks_exact(channels)
+        .map(|frame| {
+            let sum: i32 = frame.iter().map(|&s| s as i32).sum();
+            (sum / channels as i32) as i16
+        })
+        .collect()
+}
+
+/// Resample a mono signal with linear interpolation.
+pub fn resample(samples: &[i16], from_rate: u32, to_rate: u32) -> Vec<i16> {
+    if from_rate == to_rate || samples.is_empty() || from_rate == 0 {
+        return samples.to_vec();
+    }
+    let out_len = (samples.len() as u64 * to_rate as u64 / from_rate as u64) as usize;
+    let step = from_rate as f64 / to_rate as f64;
+    (0..out_len)
+        .map(|i| {
+            let pos = i as f64 * step;
+            let idx = pos as usize;
+            let frac = pos - idx as f64;
+            let a = samples[idx.min(samples.len() - 1)] as f64;
+            let b = samples[(idx + 1).min(samples.len() - 1)] as f64;
+            (a + (b - a) * frac).round() as i16
+        })
+        .collect()
+}
+
+/// Generate a stream id from the current time in milliseconds, as the
+/// Unitree examples do.
+pub fn new_stream_id() -> String {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// One chunk of a PCM stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PcmChunk<'a> {
+    pub stream_id: &'a str,
+    /// Position of the chunk in the stream.
+    pub index: usize,
+    /// Start of the chunk relative to the stream start, in milliseconds.
+    pub offset_ms: u64,
+    pub data: &'a [u8],
+}
+
+impl PcmChunk<'_> {
+    /// Wrap the chunk as an `AudioData` message stamped with its offset.
+    pub fn to_audio_data(&self) -> AudioData {
+        AudioData {
+            time_frame: self.offset_ms,
+            data: self.data.to_vec(),
+        }
+    }
+}
+
+/// Iterator over sample-aligned chunks of a PCM buffer.
+pub struct PcmChunks<'a> {
+    stream_id: &'a str,
+    pcm: &'a [u8],
+    format: WavFormat,
+    chunk_size: usize,
+    pos: usize,
+    index: usize,
+}
+
+impl<'a> PcmChunks<'a> {
+    /// Split `pcm` (in `format`) into chunks of at most `chunk_size` bytes,
+    /// rounded down to whole frames.
+    pub fn new(stream_id: &'a str, pcm: &'a [u8], format: WavFormat, chunk_size: usize) -> Self {
+        let align = format.block_align().max(1);
+        Self {
+            stream_id,
+            pcm,
+            format,
+            chunk_size: (chunk_size / align * align).max(align),
+            pos: 0,
+            index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for PcmChunks<'a> {
+    type Item = PcmChunk<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos >= self.pcm.len() {
+            return None;
+        }
+        let end = (self.pos + self.chunk_size).min(self.pcm.len());
+        let byte_rate = self.format.byte_rate().max(1) as u64;
+        let chunk = PcmChunk {
+            stream_id: self.stream_id,
+            index: self.index,
+            offset_ms: self.pos as u64 * 1000 / byte_rate,
+            data: &self.pcm[self.pos..end],
+        };
+        self.pos = end;
+        self.index += 1;
+        Some(chunk)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// RIFF/WAVE bytes from `(id, body)` chunks, padding odd bodies.
+    fn riff(chunks: &[(&[u8; 4], Vec<u8>)]) -> Vec<u8> {
+        let mut body = b"WAVE".to_vec();
+        for (id, data) in chunks {
+            body.extend_from_slice(*id);
+            body.extend_from_slice(&(data.len() as u32).to_le_bytes());
+            body.extend_from_slice(data);
+            if data.len() % 2 == 1 {
+                body.push(0);
+            }
+        }
+        let mut bytes = b"RIFF".to_vec();
+        bytes.extend_from_slice(&(body.len() as u32).to_le_bytes());
+        bytes.extend(body);
+        bytes
+    }
+
+    fn fmt(audio_format: u16, num_channels: u16, sample_rate: u32, bits_per_sample: u16) -> Vec<u8> {
+        let block_align = num_channels * bits_per_sample / 8;
+        let mut b = Vec::new();
+        b.extend_from_slice(&audio_format.to_le_bytes());
+        b.extend_from_slice(&num_channels.to_le_bytes());
+        b.extend_from_slice(&sample_rate.to_le_bytes());
+        b.extend_from_slice(&(sample_rate * block_align as u32).to_le_bytes());
+        b.extend_from_slice(&block_align.to_le_bytes());
+        b.extend_from_slice(&bits_per_sample.to_le_bytes());
+        b
+    }
+
+    fn wav(audio_format: u16, num_channels: u16, bits: u16, data: Vec<u8>) -> Wav {
+        Wav::parse(&riff(&[(b"fmt ", fmt(audio_format, num_channels, 16000, bits)), (b"data", data)])).unwrap()
+    }
+
+    #[test]
+    fn skips_odd_sized_chunks_with_padding() {
+        let bytes = riff(&[
+            (b"JUNK", vec![1, 2, 3]),
+            (b"fmt ", fmt(WAVE_FORMAT_PCM, 1, 16000, 16)),
+            (b"LIST", vec![9]),
+            (b"data", vec![1, 0, 2, 0]),
+        ]);
+        let wav = Wav::parse(&bytes).unwrap();
+        assert!(wav.is_robot_format());
+        assert_eq!(wav.data, [1, 0, 2, 0]);
+        assert_eq!(wav.to_robot_pcm().unwrap(), [1, 0, 2, 0]);
+    }
+
+    #[test]
+    fn reads_extensible_sub_format() {
+        let mut body = fmt(WAVE_FORMAT_EXTENSIBLE, 1, 16000, 32);
+        body.extend_from_slice(&22u16.to_le_bytes()); // cbSize
+        body.extend_from_slice(&32u16.to_le_bytes()); // valid bits
+        body.extend_from_slice(&4u32.to_le_bytes()); // channel mask
+        body.extend_from_slice(&WAVE_FORMAT_IEEE_FLOAT.to_le_bytes());
+        body.extend_from_slice(&[0; 14]); // rest of the GUID
+        let bytes = riff(&[(b"fmt ", body), (b"data", 0.5f32.to_le_bytes().to_vec())]);
+
+        let wav = Wav::parse(&bytes).unwrap();
+        assert_eq!(wav.format.audio_format, WAVE_FORMAT_IEEE_FLOAT);
+        assert_eq!(wav.samples().unwrap(), [16383]);
+    }
+
+    #[test]
+    fn converts_sample_widths_to_i16() {
+        let pcm8 = wav(WAVE_FORMAT_PCM, 1, 8, vec![0x80, 0xFF, 0x00]);
+        assert_eq!(pcm8.samples().unwrap(), [0, 32512, -32768]);
+
+        let pcm24 = wav(WAVE_FORMAT_PCM, 1, 24, vec![0xAA, 0x34, 0x12, 0x00, 0x00, 0x80]);
+        assert_eq!(pcm24.samples().unwrap(), [0x1234, i16::MIN]);
+
+        let pcm32 = wav(WAVE_FORMAT_PCM, 1, 32, vec![0xAA, 0xBB, 0x34, 0x12]);
+        assert_eq!(pcm32.samples().unwrap(), [0x1234]);
+
+        let float: Vec<u8> = [1.0f32, -2.0, 0.0].iter().flat_map(|v| v.to_le_bytes()).collect();
+        assert_eq!(wav(WAVE_FORMAT_IEEE_FLOAT, 1, 32, float).samples().unwrap(), [32767, -32767, 0]);
+    }
+
+    #[test]
+    fn rejects_bad_headers_and_formats() {
+        assert!(matches!(Wav::parse(b"RIFX\0\0\0\0WAVE"), Err(WavError::NotWave)));
+        assert!(matches!(Wav::parse(b"RIFF"), Err(WavError::NotWave)));
+        assert!(matches!(
+            Wav::parse(&riff(&[(b"data", vec![0, 0])])),
+            Err(WavError::MissingChunk("fmt"))
+        ));
+        assert!(matches!(
+            Wav::parse(&riff(&[(b"fmt ", fmt(WAVE_FORMAT_PCM, 1, 16000, 16))])),
+            Err(WavError::MissingChunk("data"))
+        ));
+        assert!(matches!(
+            Wav::parse(&riff(&[(b"fmt ", vec![1, 0, 1, 0])])),
+            Err(WavError::Truncated("fmt"))
+        ));
+
+        let adpcm = wav(2, 1, 16, vec![0, 0]);
+        assert!(matches!(
+            adpcm.samples(),
+            Err(WavError::Unsupported { audio_format: 2, bits_per_sample: 16 })
+        ));
+        let float64 = wav(WAVE_FORMAT_IEEE_FLOAT, 1, 64, vec![0; 8]);
+        assert!(matches!(float64.to_robot_pcm(), Err(WavError::Unsupported { .. })));
+    }
+
+    #[test]
+    fn downmixes_stereo_to_mono() {
+        assert_eq!(downmix(&[100, 300, -10, -20, 7], 2), [200, -15]);
+        assert_eq!(downmix(&[1, 2, 3], 1), [1, 2, 3]);
+    }
+
+    #[test]
+    fn resamples_48k_to_16k() {
+        let samples: Vec<i16> = (0..4800).map(|i| (i % 100) as i16).collect();
+        let out = resample(&samples, 48000, 16000);
+        assert_eq!(out.len(), 1600);
+        assert_eq!(&out[..3], &[0, 3, 6]);
+        assert_eq!(resample(&samples, 16000, 16000), samples);
+    }
+
+    #[test]
+    fn chunks_are_frame_aligned_with_offsets() {
+        let pcm = vec![0u8; 10000];
+        let chunks: Vec<_> = PcmChunks::new("42", &pcm, WavFormat::ROBOT, 3001).collect();
+        let sizes: Vec<_> = chunks.iter().map(|c| c.data.len()).collect();
+        let offsets: Vec<_> = chunks.iter().map(|c| c.offset_ms).collect();
+        assert_eq!(sizes, [3000, 3000, 3000, 1000]);
+        // 32000 bytes per second.
+        assert_eq!(offsets, [0, 93, 187, 281]);
+        assert_eq!(chunks[3].index, 3);
+        assert_eq!(chunks[3].stream_id, "42");
+        assert_eq!(chunks[1].to_audio_data().time_frame, 93);
+
+        let stereo = WavFormat { num_channels: 2, ..WavFormat::ROBOT };
+        let sizes: Vec<_> = PcmChunks::new("s", &pcm[..10], stereo, 3).map(|c| c.data.len()).collect();
+        assert_eq!(sizes, [4, 4, 2]);
+    }
+}
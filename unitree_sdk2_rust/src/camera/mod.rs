@@ -0,0 +1,20 @@
+//! Camera sources shared across robots.
+//!
+//! Go2 and B2 expose their cameras through `videohub` services that return
+//! a single JPEG sample per request. [`CameraSource`] lets frame-consuming
+//! code work with any of those clients.
+
+/// A camera that can be sampled for a JPEG frame.
+pub trait CameraSource {
+    /// Name of the camera, e.g. the service it is read from.
+    fn source_name(&self) -> &str;
+
+    /// Fetch the latest JPEG frame. Returns the encoded image or
+    /// `Err(api_error_code)`.
+    fn image_sample(&self) -> Result<Vec<u8>, i32>;
+}
+
+/// Whether `data` starts with the JPEG start-of-image marker.
+pub fn is_jpeg(data: &[u8]) -> bool {
+    data.starts_with(&[0xFF, 0xD8, 0xFF])
+}
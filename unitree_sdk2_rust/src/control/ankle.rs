@@ -0,0 +1,295 @@
+//! PR/AB joint spaces for the parallel ankles and waist of HG humanoids.
+//!
+//! The G1 and H1-2 ankles (and the G1 waist roll/pitch) are driven by two
+//! motors through a parallel linkage. `LowCmd::mode_pr` selects how the two
+//! motor slots are interpreted:
+//!
+//! - **PR**: the slots hold pitch and roll; the robot solves the linkage.
+//! - **AB**: the slots hold the A and B motors directly.
+//!
+//! The pitch slot doubles as motor B and the roll slot as motor A.
+//! [`PrAbMap`] converts between the two spaces with a linear map, which is
+//! exact for a linkage linearised around its zero pose. The SDK ships no
+//! linkage coefficients; build the map from calibrated ones.
+
+use crate::idl::hg::{LowCmd, LowState};
+
+/// How the parallel joint slots of an HG `LowCmd` are interpreted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[repr(u8)]
+pub enum AnkleMode {
+    /// Pitch/roll (series) control.
+    #[default]
+    Pr = 0,
+    /// Direct A/B motor (parallel) control.
+    Ab = 1,
+}
+
+impl AnkleMode {
+    /// Mode reported in a `LowState`, if it is a known one.
+    pub fn of(state: &LowState) -> Result<Self, u8> {
+        Self::try_from(state.mode_pr)
+    }
+
+    /// Set `cmd.mode_pr` to this mode.
+    pub fn apply(self, cmd: &mut LowCmd) {
+        cmd.mode_pr = self as u8;
+    }
+}
+
+impl TryFrom<u8> for AnkleMode {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::Pr,
+            1 => Self::Ab,
+            other => return Err(other),
+        })
+    }
+}
+
+/// A two-motor parallel joint and its `LowCmd` slots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParallelJoint {
+    LeftAnkle,
+    RightAnkle,
+    /// G1 waist roll/pitch; locked on 23-DoF and waist-locked 29-DoF units.
+    Waist,
+}
+
+impl ParallelJoint {
+    pub const ALL: [ParallelJoint; 3] = [
+        ParallelJoint::LeftAnkle,
+        ParallelJoint::RightAnkle,
+        ParallelJoint::Waist,
+    ];
+
+    /// Motor slot holding pitch in PR mode and motor B in AB mode.
+    pub fn pitch_index(self) -> usize {
+        match self {
+            ParallelJoint::LeftAnkle => 4,
+            ParallelJoint::RightAnkle => 10,
+            ParallelJoint::Waist => 14,
+        }
+    }
+
+    /// Motor slot holding roll in PR mode and motor A in AB mode.
+    pub fn roll_index(self) -> usize {
+        match self {
+            ParallelJoint::LeftAnkle => 5,
+            ParallelJoint::RightAnkle => 11,
+            ParallelJoint::Waist => 13,
+        }
+    }
+
+    /// Same slot as [`ParallelJoint::pitch_index`].
+    pub fn b_index(self) -> usize {
+        self.pitch_index()
+    }
+
+    /// Same slot as [`ParallelJoint::roll_index`].
+    pub fn a_index(self) -> usize {
+        self.roll_index()
+    }
+}
+
+/// Linear map between pitch/roll and A/B motor angles:
+///
+/// ```text
+/// [a]   [m00 m01] [pitch]
+/// [b] = [m10 m11] [roll ]
+/// ```
+///
+/// Velocities use the same map; torques use its inverse transpose so that
+/// power is preserved.
+///
+/// The crate ships no coefficients for any robot, so the matrix must come
+/// from the user. It is the linkage Jacobian at the zero pose and can be
+/// calibrated on the robot: with zero gains, hold the joint in a few poses,
+/// read the motors with `mode_pr` set to AB and then the joint angles with it
+/// set to PR, and fit `[a b] = M [pitch roll]` by least squares.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PrAbMap {
+    m: [[f32; 2]; 2],
+    inv: [[f32; 2]; 2],
+}
+
+impl PrAbMap {
+    /// Build from the PR→AB matrix. Returns `None` if it is singular.
+    pub fn new(m: [[f32; 2]; 2]) -> Option<Self> {
+        let det = m[0][0] * m[1][1] - m[0][1] * m[1][0];
+        if det.abs() < f32::EPSILON {
+            return None;
+        }
+        let inv = [
+            [m[1][1] / det, -m[0][1] / det],
+            [-m[1][0] / det, m[0][0] / det],
+        ];
+        Some(Self { m, inv })
+    }
+
+    fn mul(m: &[[f32; 2]; 2], x: f32, y: f32) -> (f32, f32) {
+        (m[0][0] * x + m[0][1] * y, m[1][0] * x + m[1][1] * y)
+    }
+
+    /// Motor angles (or velocities) `(a, b)` for `(pitch, roll)`.
+    pub fn to_ab(&self, pitch: f32, roll: f32) -> (f32, f32) {
+        Self::mul(&self.m, pitch, roll)
+    }
+
+    /// `(pitch, roll)` for motor angles (or velocities) `(a, b)`.
+    pub fn to_pr(&self, a: f32, b: f32) -> (f32, f32) {
+        Self::mul(&self.inv, a, b)
+    }
+
+    /// Motor torques `(tau_a, tau_b)` producing joint torques
+    /// `(tau_pitch, tau_roll)`.
+    pub fn torque_to_ab(&self, tau_pitch: f32, tau_roll: f32) -> (f32, f32) {
+        let inv = &self.inv;
+        (
+            inv[0][0] * tau_pitch + inv[1][0] * tau_roll,
+            inv[0][1] * tau_pitch + inv[1][1] * tau_roll,
+        )
+    }
+
+    /// Joint torques `(tau_pitch, tau_roll)` produced by motor torques
+    /// `(tau_a, tau_b)`.
+    pub fn torque_to_pr(&self, tau_a: f32, tau_b: f32) -> (f32, f32) {
+        let m = &self.m;
+        (
+            m[0][0] * tau_a + m[1][0] * tau_b,
+            m[0][1] * tau_a + m[1][1] * tau_b,
+        )
+    }
+
+    /// Rewrite the targets of `joint` in `cmd` from PR to AB space. Gains
+    /// are left unchanged. Does not touch `mode_pr`.
+    pub fn cmd_to_ab(&self, cmd: &mut LowCmd, joint: ParallelJoint) {
+        let (p, r) = (joint.pitch_index(), joint.roll_index());
+        let (pitch, roll) = (&cmd.motor_cmd[p], &cmd.motor_cmd[r]);
+        let (qa, qb) = self.to_ab(pitch.q, roll.q);
+        let (dqa, dqb) = self.to_ab(pitch.dq, roll.dq);
+        let (ta, tb) = self.torque_to_ab(pitch.tau, roll.tau);
+        let (a, b) = (joint.a_index(), joint.b_index());
+        (cmd.motor_cmd[a].q, cmd.motor_cmd[b].q) = (qa, qb);
+        (cmd.motor_cmd[a].dq, cmd.motor_cmd[b].dq) = (dqa, dqb);
+        (cmd.motor_cmd[a].tau, cmd.motor_cmd[b].tau) = (ta, tb);
+    }
+
+    /// Rewrite the targets of `joint` in `cmd` from AB to PR space. Gains
+    /// are left unchanged. Does not touch `mode_pr`.
+    pub fn cmd_to_pr(&self, cmd: &mut LowCmd, joint: ParallelJoint) {
+        let (a, b) = (joint.a_index(), joint.b_index());
+        let (ma, mb) = (&cmd.motor_cmd[a], &cmd.motor_cmd[b]);
+        let (qp, qr) = self.to_pr(ma.q, mb.q);
+        let (dqp, dqr) = self.to_pr(ma.dq, mb.dq);
+        let (tp, tr) = self.torque_to_pr(ma.tau, mb.tau);
+        let (p, r) = (joint.pitch_index(), joint.roll_index());
+        (cmd.motor_cmd[p].q, cmd.motor_cmd[r].q) = (qp, qr);
+        (cmd.motor_cmd[p].dq, cmd.motor_cmd[r].dq) = (dqp, dqr);
+        (cmd.motor_cmd[p].tau, cmd.motor_cmd[r].tau) = (tp, tr);
+    }
+
+    /// `(pitch, roll)` of `joint` from a state reported in `mode`.
+    pub fn state_pr(&self, state: &LowState, joint: ParallelJoint, mode: AnkleMode) -> (f32, f32) {
+        let (p, r) = (joint.pitch_index(), joint.roll_index());
+        let (qp, qr) = (state.motor_state[p].q, state.motor_state[r].q);
+        match mode {
+            AnkleMode::Pr => (qp, qr),
+            // In AB mode the roll slot is A and the pitch slot is B.
+            AnkleMode::Ab => self.to_pr(qr, qp),
+        }
+    }
+
+    /// `(a, b)` of `joint` from a state reported in `mode`.
+    pub fn state_ab(&self, state: &LowState, joint: ParallelJoint, mode: AnkleMode) -> (f32, f32) {
+        let (a, b) = (joint.a_index(), joint.b_index());
+        let (qa, qb) = (state.motor_state[a].q, state.motor_state[b].q);
+        match mode {
+            AnkleMode::Ab => (qa, qb),
+            // In PR mode the A slot holds roll and the B slot pitch.
+            AnkleMode::Pr => self.to_ab(qb, qa),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn map() -> PrAbMap {
+        PrAbMap::new([[1.2, 0.8], [1.1, -0.9]]).unwrap()
+    }
+
+    fn assert_close((a0, a1): (f32, f32), (b0, b1): (f32, f32)) {
+        assert!((a0 - b0).abs() < 1e-5 && (a1 - b1).abs() < 1e-5, "({a0}, {a1}) != ({b0}, {b1})");
+    }
+
+    #[test]
+    fn new_rejects_singular_matrix() {
+        assert!(PrAbMap::new([[1.0, 2.0], [2.0, 4.0]]).is_none());
+        assert!(PrAbMap::new([[0.0, 0.0], [0.0, 0.0]]).is_none());
+    }
+
+    #[test]
+    fn to_pr_inverts_to_ab() {
+        let map = map();
+        let (a, b) = map.to_ab(0.3, -0.2);
+        assert_close(map.to_pr(a, b), (0.3, -0.2));
+    }
+
+    #[test]
+    fn torques_preserve_power() {
+        let map = map();
+        let (dq_pr, tau_pr) = ((0.3, -0.2), (5.0, -2.0));
+        let dq_ab = map.to_ab(dq_pr.0, dq_pr.1);
+        let tau_ab = map.torque_to_ab(tau_pr.0, tau_pr.1);
+        let power_pr = tau_pr.0 * dq_pr.0 + tau_pr.1 * dq_pr.1;
+        let power_ab = tau_ab.0 * dq_ab.0 + tau_ab.1 * dq_ab.1;
+        assert!((power_pr - power_ab).abs() < 1e-5);
+        assert_close(map.torque_to_pr(tau_ab.0, tau_ab.1), tau_pr);
+    }
+
+    #[test]
+    fn cmd_round_trip_is_identity() {
+        let map = map();
+        let joint = ParallelJoint::LeftAnkle;
+        let mut cmd = LowCmd::default();
+        let (p, r) = (joint.pitch_index(), joint.roll_index());
+        (cmd.motor_cmd[p].q, cmd.motor_cmd[p].dq, cmd.motor_cmd[p].tau) = (0.2, 0.1, 1.0);
+        (cmd.motor_cmd[r].q, cmd.motor_cmd[r].dq, cmd.motor_cmd[r].tau) = (-0.1, 0.4, -3.0);
+        let before = cmd.clone();
+
+        map.cmd_to_ab(&mut cmd, joint);
+        let (qa, qb) = map.to_ab(0.2, -0.1);
+        assert_close((cmd.motor_cmd[joint.a_index()].q, cmd.motor_cmd[joint.b_index()].q), (qa, qb));
+
+        map.cmd_to_pr(&mut cmd, joint);
+        for i in [p, r] {
+            let (m, m0) = (&cmd.motor_cmd[i], &before.motor_cmd[i]);
+            assert_close((m.q, m.dq), (m0.q, m0.dq));
+            assert_close((m.tau, 0.0), (m0.tau, 0.0));
+        }
+    }
+
+    #[test]
+    fn state_reads_slots_per_mode() {
+        let map = map();
+        let joint = ParallelJoint::RightAnkle;
+        let (pitch, roll) = (0.2, -0.1);
+        let (a, b) = map.to_ab(pitch, roll);
+
+        let mut pr_state = LowState::default();
+        pr_state.motor_state[joint.pitch_index()].q = pitch;
+        pr_state.motor_state[joint.roll_index()].q = roll;
+        assert_eq!(map.state_pr(&pr_state, joint, AnkleMode::Pr), (pitch, roll));
+        assert_close(map.state_ab(&pr_state, joint, AnkleMode::Pr), (a, b));
+
+        let mut ab_state = LowState::default();
+        ab_state.motor_state[joint.a_index()].q = a;
+        ab_state.motor_state[joint.b_index()].q = b;
+        assert_eq!(map.state_ab(&ab_state, joint, AnkleMode::Ab), (a, b));
+        assert_close(map.state_pr(&ab_state, joint, AnkleMode::Ab), (pitch, roll));
+    }
+}
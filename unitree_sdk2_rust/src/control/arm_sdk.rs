@@ -0,0 +1,540 @@
+//! ArmSdk - arm control on top of the humanoid locomotion controller.
+//!
+//! G1 and H1-2 accept HG `LowCmd`s on `rt/arm_sdk` while walking. The `q`
+//! of one otherwise unused motor slot carries a blend weight in [0, 1]: at
+//! 0 the locomotion controller owns the arms, at 1 the arm commands do.
+//! [`ArmSdk`] ramps that weight in and out, rate-limits the joint targets,
+//! and ramps the weight back to 0 when dropped so the arms are never left
+//! stiff in a stale pose.
+//!
+//! The original H1 also has an `rt/arm_sdk` topic, but it takes `unitree_go`
+//! `LowCmd`s with the weight in slot 9 (`h1_arm_sdk_dds_example.cpp`); it is
+//! not supported here.
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::channel::{ChannelFactory, ChannelPublisher, ChannelSubscriber};
+use crate::error::{Result, SdkError};
+use crate::idl::crc::Crc;
+use crate::idl::hg::{LowCmd, LowState};
+
+pub const TOPIC_ARM_SDK: &str = "rt/arm_sdk";
+
+/// A set of joints controllable through `rt/arm_sdk`.
+pub trait ArmJoint: Copy + 'static {
+    /// Every joint of the layout, in command order.
+    const ALL: &'static [Self];
+    /// `LowCmd` motor slot whose `q` carries the blend weight.
+    const WEIGHT_INDEX: usize;
+
+    /// Motor index in `LowCmd`/`LowState`.
+    fn index(self) -> usize;
+
+    /// Default `(kp, kd)`. The G1 examples use 60/1.5 on every joint.
+    fn default_gains(self) -> (f32, f32) {
+        (60.0, 1.5)
+    }
+}
+
+/// G1 arm and waist joints for the 5-DoF arm (23-DoF body), as in
+/// `g1_arm5_sdk_dds_example.cpp`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(usize)]
+pub enum G1Arm5Joint {
+    LeftShoulderPitch = 15,
+    LeftShoulderRoll = 16,
+    LeftShoulderYaw = 17,
+    LeftElbowPitch = 18,
+    LeftElbowRoll = 19,
+    RightShoulderPitch = 22,
+    RightShoulderRoll = 23,
+    RightShoulderYaw = 24,
+    RightElbowPitch = 25,
+    RightElbowRoll = 26,
+    WaistYaw = 12,
+    WaistRoll = 13,
+    WaistPitch = 14,
+}
+
+impl ArmJoint for G1Arm5Joint {
+    const ALL: &'static [Self] = &[
+        G1Arm5Joint::LeftShoulderPitch,
+        G1Arm5Joint::LeftShoulderRoll,
+        G1Arm5Joint::LeftShoulderYaw,
+        G1Arm5Joint::LeftElbowPitch,
+        G1Arm5Joint::LeftElbowRoll,
+        G1Arm5Joint::RightShoulderPitch,
+        G1Arm5Joint::RightShoulderRoll,
+        G1Arm5Joint::RightShoulderYaw,
+        G1Arm5Joint::RightElbowPitch,
+        G1Arm5Joint::RightElbowRoll,
+        G1Arm5Joint::WaistYaw,
+        G1Arm5Joint::WaistRoll,
+        G1Arm5Joint::WaistPitch,
+    ];
+    const WEIGHT_INDEX: usize = 29;
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// G1 arm and waist joints for the 7-DoF arm (29-DoF body), as in
+/// `g1_arm7_sdk_dds_example.cpp`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(usize)]
+pub enum G1Arm7Joint {
+    LeftShoulderPitch = 15,
+    LeftShoulderRoll = 16,
+    LeftShoulderYaw = 17,
+    LeftElbow = 18,
+    LeftWristRoll = 19,
+    LeftWristPitch = 20,
+    LeftWristYaw = 21,
+    RightShoulderPitch = 22,
+    RightShoulderRoll = 23,
+    RightShoulderYaw = 24,
+    RightElbow = 25,
+    RightWristRoll = 26,
+    RightWristPitch = 27,
+    RightWristYaw = 28,
+    WaistYaw = 12,
+    WaistRoll = 13,
+    WaistPitch = 14,
+}
+
+impl ArmJoint for G1Arm7Joint {
+    const ALL: &'static [Self] = &[
+        G1Arm7Joint::LeftShoulderPitch,
+        G1Arm7Joint::LeftShoulderRoll,
+        G1Arm7Joint::LeftShoulderYaw,
+        G1Arm7Joint::LeftElbow,
+        G1Arm7Joint::LeftWristRoll,
+        G1Arm7Joint::LeftWristPitch,
+        G1Arm7Joint::LeftWristYaw,
+        G1Arm7Joint::RightShoulderPitch,
+        G1Arm7Joint::RightShoulderRoll,
+        G1Arm7Joint::RightShoulderYaw,
+        G1Arm7Joint::RightElbow,
+        G1Arm7Joint::RightWristRoll,
+        G1Arm7Joint::RightWristPitch,
+        G1Arm7Joint::RightWristYaw,
+        G1Arm7Joint::WaistYaw,
+        G1Arm7Joint::WaistRoll,
+        G1Arm7Joint::WaistPitch,
+    ];
+    const WEIGHT_INDEX: usize = 29;
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// H1-2 arm and waist joints, as in `h1_2_arm_sdk_dds_example.cpp`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(usize)]
+pub enum H1_2ArmJoint {
+    LeftShoulderPitch = 13,
+    LeftShoulderRoll = 14,
+    LeftShoulderYaw = 15,
+    LeftElbow = 16,
+    LeftWristRoll = 17,
+    LeftWristPitch = 18,
+    LeftWristYaw = 19,
+    RightShoulderPitch = 20,
+    RightShoulderRoll = 21,
+    RightShoulderYaw = 22,
+    RightElbow = 23,
+    RightWristRoll = 24,
+    RightWristPitch = 25,
+    RightWristYaw = 26,
+    WaistYaw = 12,
+}
+
+impl ArmJoint for H1_2ArmJoint {
+    const ALL: &'static [Self] = &[
+        H1_2ArmJoint::LeftShoulderPitch,
+        H1_2ArmJoint::LeftShoulderRoll,
+        H1_2ArmJoint::LeftShoulderYaw,
+        H1_2ArmJoint::LeftElbow,
+        H1_2ArmJoint::LeftWristRoll,
+        H1_2ArmJoint::LeftWristPitch,
+        H1_2ArmJoint::LeftWristYaw,
+        H1_2ArmJoint::RightShoulderPitch,
+        H1_2ArmJoint::RightShoulderRoll,
+        H1_2ArmJoint::RightShoulderYaw,
+        H1_2ArmJoint::RightElbow,
+        H1_2ArmJoint::RightWristRoll,
+        H1_2ArmJoint::RightWristPitch,
+        H1_2ArmJoint::RightWristYaw,
+        H1_2ArmJoint::WaistYaw,
+    ];
+    const WEIGHT_INDEX: usize = 27;
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Gains from `h1_2_arm_sdk_dds_example.cpp`.
+    fn default_gains(self) -> (f32, f32) {
+        match self {
+            H1_2ArmJoint::WaistYaw => (200.0, 2.0),
+            H1_2ArmJoint::LeftShoulderPitch
+            | H1_2ArmJoint::LeftShoulderRoll
+            | H1_2ArmJoint::RightShoulderPitch
+            | H1_2ArmJoint::RightShoulderRoll => (120.0, 2.0),
+            H1_2ArmJoint::LeftShoulderYaw | H1_2ArmJoint::RightShoulderYaw => (80.0, 1.5),
+            _ => (50.0, 1.0),
+        }
+    }
+}
+
+/// Move `value` towards `target` by at most `max_delta`, landing exactly
+/// on `target` once within reach.
+fn approach(value: f32, target: f32, max_delta: f32) -> f32 {
+    if (target - value).abs() <= max_delta {
+        target
+    } else {
+        value + max_delta.copysign(target - value)
+    }
+}
+
+/// Rates and timing for [`ArmSdk`]. Checked by [`ArmSdk::new`]: rates and
+/// `control_dt` must be positive. Gains are per joint; see
+/// [`ArmJoint::default_gains`] and [`ArmSdk::set_gains`].
+#[derive(Debug, Clone)]
+pub struct ArmSdkConfig {
+    pub topic: String,
+    pub state_topic: String,
+    /// Blend weight change per second.
+    pub weight_rate: f32,
+    /// Max joint target change per second (rad/s).
+    pub max_joint_velocity: f32,
+    /// Period used by the blocking helpers.
+    pub control_dt: Duration,
+}
+
+impl Default for ArmSdkConfig {
+    fn default() -> Self {
+        Self {
+            topic: TOPIC_ARM_SDK.to_string(),
+            state_topic: "rt/lowstate".to_string(),
+            weight_rate: 0.2,
+            max_joint_velocity: 0.5,
+            control_dt: Duration::from_millis(20),
+        }
+    }
+}
+
+/// Publishes arm targets on `rt/arm_sdk` with a ramped blend weight.
+///
+/// Call [`ArmSdk::engage`] once a state has arrived, set targets, and call
+/// [`ArmSdk::step`] every control period. [`ArmSdk::release`] (or dropping
+/// the value) ramps the weight back to 0.
+pub struct ArmSdk<J: ArmJoint> {
+    config: ArmSdkConfig,
+    cmd: LowCmd,
+    weight: f32,
+    target_weight: f32,
+    setpoint: Vec<f32>,
+    target: Vec<f32>,
+    gains: Vec<(f32, f32)>,
+    latest: Arc<Mutex<Option<LowState>>>,
+    publisher: ChannelPublisher<LowCmd>,
+    subscriber: ChannelSubscriber<LowState>,
+    _joints: std::marker::PhantomData<J>,
+}
+
+impl ArmSdkConfig {
+    fn validate(&self) -> Result<()> {
+        let rates_ok = [self.weight_rate, self.max_joint_velocity]
+            .iter()
+            .all(|r| r.is_finite() && *r > 0.0);
+        if !rates_ok || self.control_dt.is_zero() {
+            return Err(SdkError::Init(format!("invalid ArmSdkConfig: {:?}", self)));
+        }
+        Ok(())
+    }
+}
+
+impl<J: ArmJoint> ArmSdk<J> {
+    /// Create the channels. Nothing is published until [`ArmSdk::step`].
+    /// Fails with `Init` if `config` is invalid.
+    pub fn new(config: ArmSdkConfig) -> Result<Self> {
+        config.validate()?;
+        let factory = ChannelFactory::instance().lock().unwrap();
+        let mut publisher: ChannelPublisher<LowCmd> = factory.create_publisher(&config.topic);
+        let mut subscriber: ChannelSubscriber<LowState> =
+            factory.create_subscriber(&config.state_topic);
+        drop(factory);
+
+        publisher.init_channel()?;
+        let latest = Arc::new(Mutex::new(None));
+        {
+            let latest = Arc::clone(&latest);
+            subscriber.init_channel(move |state: &LowState| {
+                *latest.lock().unwrap() = Some(state.clone());
+            })?;
+        }
+
+        let n = J::ALL.len();
+        Ok(Self {
+            config,
+            cmd: LowCmd::default(),
+            weight: 0.0,
+            target_weight: 0.0,
+            setpoint: vec![0.0; n],
+            target: vec![0.0; n],
+            gains: J::ALL.iter().map(|j| j.default_gains()).collect(),
+            latest,
+            publisher,
+            subscriber,
+            _joints: std::marker::PhantomData,
+        })
+    }
+
+    /// Latest `LowState` received, if any.
+    pub fn latest_state(&self) -> Option<LowState> {
+        self.latest.lock().unwrap().clone()
+    }
+
+    /// Current blend weight.
+    pub fn weight(&self) -> f32 {
+        self.weight
+    }
+
+    /// Whether the weight is ramping or held above 0.
+    pub fn is_engaged(&self) -> bool {
+        self.weight > 0.0 || self.target_weight > 0.0
+    }
+
+    /// Start from the measured arm pose and ramp the weight up to 1.
+    /// Fails with `NotInitialized` until a `LowState` has arrived.
+    pub fn engage(&mut self) -> Result<()> {
+        let state = self.latest_state().ok_or(SdkError::NotInitialized)?;
+        if self.weight == 0.0 {
+            for (i, joint) in J::ALL.iter().enumerate() {
+                let q = state.motor_state[joint.index()].q;
+                self.setpoint[i] = q;
+                self.target[i] = q;
+            }
+        }
+        self.target_weight = 1.0;
+        Ok(())
+    }
+
+    /// Ramp the weight down to 0 on the following steps.
+    pub fn release(&mut self) {
+        self.target_weight = 0.0;
+    }
+
+    /// Set one joint's target. Returns false, leaving the target
+    /// unchanged, if `q` is not finite.
+    pub fn set_target(&mut self, joint: J, q: f32) -> bool {
+        if !q.is_finite() {
+            return false;
+        }
+        if let Some(i) = J::ALL.iter().position(|j| j.index() == joint.index()) {
+            self.target[i] = q;
+        }
+        true
+    }
+
+    /// Set every joint's target, in `J::ALL` order. Extra values are
+    /// ignored. Returns false, leaving the targets unchanged, if any value
+    /// is not finite.
+    pub fn set_targets(&mut self, q: &[f32]) -> bool {
+        if !q.iter().all(|v| v.is_finite()) {
+            return false;
+        }
+        for (target, &q) in self.target.iter_mut().zip(q) {
+            *target = q;
+        }
+        true
+    }
+
+    /// Set one joint's `kp`/`kd`. Returns false, leaving the gains
+    /// unchanged, unless both are finite and non-negative.
+    pub fn set_gains(&mut self, joint: J, kp: f32, kd: f32) -> bool {
+        if ![kp, kd].iter().all(|g| g.is_finite() && *g >= 0.0) {
+            return false;
+        }
+        if let Some(i) = J::ALL.iter().position(|j| j.index() == joint.index()) {
+            self.gains[i] = (kp, kd);
+        }
+        true
+    }
+
+    /// Per-joint `(kp, kd)`, in `J::ALL` order.
+    pub fn gains(&self) -> &[(f32, f32)] {
+        &self.gains
+    }
+
+    /// Rate-limited targets sent on the last step.
+    pub fn setpoint(&self) -> &[f32] {
+        &self.setpoint
+    }
+
+    /// Advance the weight ramp and setpoints by `dt` seconds and publish.
+    pub fn step(&mut self, dt: f32) -> Result<()> {
+        self.weight = approach(self.weight, self.target_weight, self.config.weight_rate * dt).clamp(0.0, 1.0);
+
+        let max_delta = self.config.max_joint_velocity * dt;
+        for (i, joint) in J::ALL.iter().enumerate() {
+            self.setpoint[i] = approach(self.setpoint[i], self.target[i], max_delta);
+            let m = &mut self.cmd.motor_cmd[joint.index()];
+            m.q = self.setpoint[i];
+            m.dq = 0.0;
+            m.tau = 0.0;
+            (m.kp, m.kd) = self.gains[i];
+        }
+        self.cmd.motor_cmd[J::WEIGHT_INDEX].q = self.weight;
+
+        self.cmd.stamp_crc();
+        self.publisher.write(&self.cmd)?;
+        Ok(())
+    }
+
+    /// Steps at `control_dt` needed for the setpoints and weight to reach
+    /// their targets, plus a margin for rounding.
+    fn steps_to_settle(&self) -> usize {
+        let dt = self.config.control_dt.as_secs_f32();
+        let joint_dist = self
+            .setpoint
+            .iter()
+            .zip(&self.target)
+            .map(|(s, t)| (t - s).abs())
+            .fold(0.0, f32::max);
+        let weight_dist = (self.target_weight - self.weight).abs();
+        let steps = (joint_dist / (self.config.max_joint_velocity * dt))
+            .max(weight_dist / (self.config.weight_rate * dt))
+            .ceil();
+        steps as usize + 2
+    }
+
+    /// Step at `control_dt` until the setpoints reach the targets and the
+    /// weight its target. Fails with `Timeout` if they have not after the
+    /// number of steps the configured rates need.
+    pub fn settle(&mut self) -> Result<()> {
+        let dt = self.config.control_dt;
+        for _ in 0..self.steps_to_settle() {
+            self.step(dt.as_secs_f32())?;
+            let reached = self
+                .setpoint
+                .iter()
+                .zip(&self.target)
+                .all(|(s, t)| s == t);
+            if reached && self.weight == self.target_weight {
+                return Ok(());
+            }
+            std::thread::sleep(dt);
+        }
+        Err(SdkError::Timeout)
+    }
+
+    /// Ramp the weight to 0, blocking until done. Fails with `Timeout` if
+    /// the weight has not reached 0 after the steps `weight_rate` needs.
+    pub fn release_blocking(&mut self) -> Result<()> {
+        self.release();
+        let dt = self.config.control_dt;
+        let max_steps = (self.weight / (self.config.weight_rate * dt.as_secs_f32())).ceil() as usize + 2;
+        for _ in 0..max_steps {
+            if self.weight <= 0.0 {
+                break;
+            }
+            self.step(dt.as_secs_f32())?;
+            std::thread::sleep(dt);
+        }
+        if self.weight > 0.0 {
+            return Err(SdkError::Timeout);
+        }
+        // One last message with weight 0, as in the C++ example.
+        self.step(0.0)
+    }
+}
+
+impl<J: ArmJoint> Drop for ArmSdk<J> {
+    fn drop(&mut self) {
+        if self.weight > 0.0 {
+            log::info!("ArmSdk: releasing arms");
+            if let Err(e) = self.release_blocking() {
+                log::error!("ArmSdk: release failed: {}", e);
+            }
+        }
+        self.subscriber.close_channel();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fast_config() -> ArmSdkConfig {
+        ArmSdkConfig {
+            topic: "rt/arm_sdk_test".to_string(),
+            state_topic: "rt/lowstate_test".to_string(),
+            weight_rate: 100.0,
+            max_joint_velocity: 100.0,
+            control_dt: Duration::from_millis(1),
+        }
+    }
+
+    #[test]
+    fn weight_slot_is_not_an_arm_joint() {
+        fn check<J: ArmJoint>() {
+            assert!(J::ALL.iter().all(|j| j.index() != J::WEIGHT_INDEX));
+        }
+        check::<G1Arm5Joint>();
+        check::<G1Arm7Joint>();
+        check::<H1_2ArmJoint>();
+        assert!(G1Arm7Joint::ALL.contains(&G1Arm7Joint::WaistPitch));
+    }
+
+    #[test]
+    fn new_rejects_invalid_config() {
+        for config in [
+            ArmSdkConfig { weight_rate: 0.0, ..fast_config() },
+            ArmSdkConfig { max_joint_velocity: f32::NAN, ..fast_config() },
+            ArmSdkConfig { control_dt: Duration::ZERO, ..fast_config() },
+        ] {
+            assert!(matches!(ArmSdk::<G1Arm7Joint>::new(config), Err(SdkError::Init(_))));
+        }
+    }
+
+    #[test]
+    fn settle_and_release_terminate() {
+        let mut arms = ArmSdk::<H1_2ArmJoint>::new(fast_config()).unwrap();
+        arms.target_weight = 1.0;
+        assert!(!arms.set_target(H1_2ArmJoint::LeftElbow, f32::NAN));
+        assert!(arms.set_target(H1_2ArmJoint::LeftElbow, 0.5));
+        arms.settle().unwrap();
+        assert_eq!(arms.weight(), 1.0);
+        assert_eq!(arms.setpoint()[3], 0.5);
+
+        arms.release_blocking().unwrap();
+        assert_eq!(arms.cmd.motor_cmd[12].kp, 200.0);
+        assert_eq!(arms.cmd.motor_cmd[16].kd, 1.0);
+        assert_eq!(arms.weight(), 0.0);
+        assert!(!arms.is_engaged());
+    }
+
+    #[test]
+    fn gains_follow_the_c_examples() {
+        let mut arms = ArmSdk::<H1_2ArmJoint>::new(fast_config()).unwrap();
+        let gains: Vec<_> = H1_2ArmJoint::ALL.iter().map(|j| j.default_gains()).collect();
+        assert_eq!(arms.gains(), gains.as_slice());
+        assert_eq!(
+            &gains[..7],
+            &[(120.0, 2.0), (120.0, 2.0), (80.0, 1.5), (50.0, 1.0), (50.0, 1.0), (50.0, 1.0), (50.0, 1.0)]
+        );
+        assert_eq!(gains[..7], gains[7..14]);
+        assert_eq!(gains[14], (200.0, 2.0));
+        assert_eq!(G1Arm7Joint::WaistYaw.default_gains(), (60.0, 1.5));
+
+        assert!(!arms.set_gains(H1_2ArmJoint::LeftElbow, -1.0, 1.0));
+        assert!(!arms.set_gains(H1_2ArmJoint::LeftElbow, 10.0, f32::NAN));
+        assert!(arms.set_gains(H1_2ArmJoint::LeftElbow, 10.0, 0.5));
+        assert_eq!(arms.gains()[3], (10.0, 0.5));
+    }
+}
@@ -0,0 +1,112 @@
+//! HgLowCmdPublisher - HG `LowCmd` publisher that fills in the robot mode.
+//!
+//! An HG robot ignores `LowCmd`s whose `mode_machine` differs from the one
+//! it reports in `LowState`. The publisher watches `rt/lowstate`, and on
+//! every write echoes the latest `mode_machine`, sets `mode_pr` from its
+//! [`AnkleMode`] and stamps the CRC.
+
+use std::sync::{Arc, Mutex};
+
+use crate::channel::{ChannelFactory, ChannelPublisher, ChannelSubscriber};
+use crate::error::{Result, SdkError};
+use crate::idl::crc::Crc;
+use crate::idl::hg::{LowCmd, LowState};
+use super::ankle::AnkleMode;
+
+/// Publishes HG `LowCmd`s with `mode_pr`, `mode_machine` and CRC set.
+pub struct HgLowCmdPublisher {
+    mode: AnkleMode,
+    latest: Arc<Mutex<Option<LowState>>>,
+    publisher: ChannelPublisher<LowCmd>,
+    subscriber: ChannelSubscriber<LowState>,
+}
+
+impl HgLowCmdPublisher {
+    /// Create the command publisher on `cmd_topic` and watch `state_topic`
+    /// for `mode_machine`.
+    pub fn new(cmd_topic: &str, state_topic: &str, mode: AnkleMode) -> Result<Self> {
+        let factory = ChannelFactory::instance().lock().unwrap();
+        let mut publisher: ChannelPublisher<LowCmd> = factory.create_publisher(cmd_topic);
+        let mut subscriber: ChannelSubscriber<LowState> = factory.create_subscriber(state_topic);
+        drop(factory);
+
+        publisher.init_channel()?;
+        let latest: Arc<Mutex<Option<LowState>>> = Arc::new(Mutex::new(None));
+        {
+            let latest = Arc::clone(&latest);
+            subscriber.init_channel(move |state: &LowState| {
+                let mut latest = latest.lock().unwrap();
+                if latest.as_ref().map(|s| s.mode_machine) != Some(state.mode_machine) {
+                    log::info!("HG mode_machine: {}", state.mode_machine);
+                }
+                *latest = Some(state.clone());
+            })?;
+        }
+
+        Ok(Self {
+            mode,
+            latest,
+            publisher,
+            subscriber,
+        })
+    }
+
+    /// Ankle mode written to `mode_pr`.
+    pub fn mode(&self) -> AnkleMode {
+        self.mode
+    }
+
+    /// Change the ankle mode used by later writes.
+    pub fn set_mode(&mut self, mode: AnkleMode) {
+        self.mode = mode;
+    }
+
+    /// Latest `LowState` received, if any.
+    pub fn latest_state(&self) -> Option<LowState> {
+        self.latest.lock().unwrap().clone()
+    }
+
+    /// Latest `mode_machine` reported by the robot, if any.
+    pub fn mode_machine(&self) -> Option<u8> {
+        self.latest.lock().unwrap().as_ref().map(|s| s.mode_machine)
+    }
+
+    /// Fill in `mode_pr`, `mode_machine` and `crc`, then publish. Fails
+    /// with `NotInitialized` until the robot has reported its
+    /// `mode_machine`, since the command would be dropped anyway.
+    pub fn write(&mut self, cmd: &mut LowCmd) -> Result<bool> {
+        cmd.mode_machine = self.mode_machine().ok_or(SdkError::NotInitialized)?;
+        self.mode.apply(cmd);
+        cmd.stamp_crc();
+        self.publisher.write(cmd)
+    }
+}
+
+impl Drop for HgLowCmdPublisher {
+    fn drop(&mut self) {
+        self.subscriber.close_channel();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_waits_for_mode_machine() {
+        let mut publisher =
+            HgLowCmdPublisher::new("rt/lowcmd_hg_test", "rt/lowstate_hg_test", AnkleMode::Ab).unwrap();
+        let mut cmd = LowCmd::default();
+        assert!(matches!(publisher.write(&mut cmd), Err(SdkError::NotInitialized)));
+
+        let state = LowState {
+            mode_machine: 5,
+            ..Default::default()
+        };
+        *publisher.latest.lock().unwrap() = Some(state);
+        publisher.write(&mut cmd).unwrap();
+        assert_eq!(cmd.mode_machine, 5);
+        assert_eq!(cmd.mode_pr, AnkleMode::Ab as u8);
+        assert_eq!(cmd.crc, cmd.compute_crc());
+    }
+}
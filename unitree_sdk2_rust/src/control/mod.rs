@@ -0,0 +1,12 @@
+//! Low-level control helpers.
+pub mod ankle;
+pub mod arm_sdk;
+pub mod hg_publisher;
+pub mod runner;
+pub mod state_machine;
+
+pub use ankle::{AnkleMode, ParallelJoint, PrAbMap};
+pub use arm_sdk::{ArmJoint, ArmSdk, ArmSdkConfig, G1Arm5Joint, G1Arm7Joint, H1_2ArmJoint};
+pub use hg_publisher::HgLowCmdPublisher;
+pub use runner::{ControlRunner, Controller, RunnerConfig, StopHandle, StopReason};
+pub use state_machine::{Phase, StateMachine, StateMachineConfig};
@@ -0,0 +1,510 @@
+//! ControlRunner - fixed-rate loop around a user [`Controller`].
+//!
+//! The runner subscribes to the state topic, calls the controller at a fixed
+//! period, stamps the CRC and publishes the command. It stops the loop and
+//! damps the motors when:
+//!
+//! - a stop is requested through a [`StopHandle`],
+//! - no new state has arrived for `stale_timeout`,
+//! - an optional [`SafetyMonitor`] reports a fault.
+//!
+//! On every exit path the controller's `on_stop` runs and a damping command
+//! is published for `shutdown_duration`. If the loop unwinds, e.g. on a
+//! panic in `compute`, the runner's `Drop` publishes the damping instead,
+//! then runs `on_stop`.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::channel::{ChannelFactory, ChannelPublisher, ChannelSubscriber};
+use crate::error::{Result, SdkError};
+use crate::idl::crc::Crc;
+use crate::safety::monitors::{DampingCommand, Fault, LowStateView, SafetyMonitor};
+
+/// Low-level controller driven by [`ControlRunner`].
+///
+/// `S` is the state message (e.g. `go2::LowState`) and `C` the command
+/// message (e.g. `go2::LowCmd`).
+pub trait Controller<S, C> {
+    /// Called once with the first state, before the first `compute`.
+    fn on_start(&mut self, _state: &S) {}
+
+    /// Fill `cmd` from the latest `state`. `dt` is the time since the
+    /// previous call, in seconds.
+    fn compute(&mut self, state: &S, cmd: &mut C, dt: f32);
+
+    /// Called once when the loop ends, before the runner damps the motors.
+    fn on_stop(&mut self) {}
+}
+
+/// Why [`ControlRunner::run`] returned.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StopReason {
+    /// [`StopHandle::stop`] was called.
+    Requested,
+    /// No new state arrived within `stale_timeout`.
+    StaleState(Duration),
+    /// The safety monitor fired.
+    Fault(Vec<Fault>),
+}
+
+/// Loop timing and shutdown settings.
+#[derive(Debug, Clone)]
+pub struct RunnerConfig {
+    pub cmd_topic: String,
+    pub state_topic: String,
+    /// Control period.
+    pub period: Duration,
+    /// Max age of the latest state before the loop stops.
+    pub stale_timeout: Duration,
+    /// How long to wait for the first state.
+    pub startup_timeout: Duration,
+    /// Damping gain published on shutdown.
+    pub damping_kd: f32,
+    /// How long to keep publishing damping on shutdown.
+    pub shutdown_duration: Duration,
+}
+
+impl Default for RunnerConfig {
+    fn default() -> Self {
+        Self {
+            cmd_topic: "rt/lowcmd".to_string(),
+            state_topic: "rt/lowstate".to_string(),
+            period: Duration::from_millis(2),
+            stale_timeout: Duration::from_millis(100),
+            startup_timeout: Duration::from_secs(5),
+            damping_kd: 2.0,
+            shutdown_duration: Duration::from_millis(500),
+        }
+    }
+}
+
+/// Requests a running [`ControlRunner`] to stop. Cloneable and `Send`, so
+/// it can be moved to a signal handler or another thread.
+#[derive(Debug, Clone, Default)]
+pub struct StopHandle(Arc<AtomicBool>);
+
+impl StopHandle {
+    /// Ask the loop to stop at the next tick.
+    pub fn stop(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    /// Whether a stop has been requested.
+    pub fn is_stopped(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+/// Latest state, when it arrived, and a sequence number to spot new ones.
+struct Received<S> {
+    state: S,
+    at: Instant,
+    seq: u64,
+}
+
+/// Fixed-rate runner wiring a [`Controller`] to the low-level channels.
+pub struct ControlRunner<S, C, K>
+where
+    S: Clone + Send + LowStateView + 'static,
+    C: Crc + DampingCommand,
+    K: Controller<S, C>,
+{
+    controller: K,
+    config: RunnerConfig,
+    cmd: C,
+    monitor: Option<SafetyMonitor>,
+    stop: StopHandle,
+    /// Set while the loop may have left the motors stiff.
+    running: bool,
+    latest: Arc<Mutex<Option<Received<S>>>>,
+    publisher: ChannelPublisher<C>,
+    subscriber: ChannelSubscriber<S>,
+}
+
+impl<S, C, K> ControlRunner<S, C, K>
+where
+    S: Clone + Send + LowStateView + 'static,
+    C: Crc + DampingCommand,
+    K: Controller<S, C>,
+{
+    /// Create the channels. `cmd` is the initial command (header, motor
+    /// modes) that the controller updates on every tick.
+    pub fn new(controller: K, cmd: C, config: RunnerConfig) -> Result<Self> {
+        let factory = ChannelFactory::instance().lock().unwrap();
+        let mut publisher: ChannelPublisher<C> = factory.create_publisher(&config.cmd_topic);
+        let mut subscriber: ChannelSubscriber<S> = factory.create_subscriber(&config.state_topic);
+        drop(factory);
+
+        publisher.init_channel()?;
+        let latest: Arc<Mutex<Option<Received<S>>>> = Arc::new(Mutex::new(None));
+        {
+            let latest = Arc::clone(&latest);
+            subscriber.init_channel(move |state: &S| {
+                let mut latest = latest.lock().unwrap();
+                let seq = latest.as_ref().map_or(0, |r| r.seq + 1);
+                *latest = Some(Received {
+                    state: state.clone(),
+                    at: Instant::now(),
+                    seq,
+                });
+            })?;
+        }
+
+        Ok(Self {
+            controller,
+            config,
+            cmd,
+            monitor: None,
+            stop: StopHandle::default(),
+            running: false,
+            latest,
+            publisher,
+            subscriber,
+        })
+    }
+
+    /// Also stop on faults reported by `monitor`. Its `LostConnection`
+    /// condition, if any, is checked against state arrival as well.
+    pub fn with_monitor(mut self, monitor: SafetyMonitor) -> Self {
+        self.monitor = Some(monitor);
+        self
+    }
+
+    /// Stop on `stop` instead of a fresh handle, e.g. one the controller
+    /// already holds.
+    pub fn with_stop_handle(mut self, stop: StopHandle) -> Self {
+        self.stop = stop;
+        self
+    }
+
+    /// Handle for stopping the loop.
+    pub fn stop_handle(&self) -> StopHandle {
+        self.stop.clone()
+    }
+
+    /// The controller.
+    pub fn controller(&self) -> &K {
+        &self.controller
+    }
+
+    fn snapshot(&self) -> Option<(S, Instant, u64)> {
+        self.latest
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|r| (r.state.clone(), r.at, r.seq))
+    }
+
+    fn publish(&mut self) -> Result<()> {
+        self.cmd.stamp_crc();
+        self.publisher.write(&self.cmd)?;
+        Ok(())
+    }
+
+    /// Wait for the first state, then run the controller until a stop
+    /// condition. Returns why the loop stopped; channel errors are returned
+    /// as `Err` after the motors have been damped.
+    pub fn run(&mut self) -> Result<StopReason> {
+        let startup = Instant::now();
+        let (first, _, _) = loop {
+            if let Some(snapshot) = self.snapshot() {
+                break snapshot;
+            }
+            if self.stop.is_stopped() {
+                return Ok(StopReason::Requested);
+            }
+            if startup.elapsed() > self.config.startup_timeout {
+                log::error!("ControlRunner: no state on {}", self.config.state_topic);
+                return Err(SdkError::Timeout);
+            }
+            std::thread::sleep(self.config.period);
+        };
+
+        self.running = true;
+        self.controller.on_start(&first);
+        log::info!(
+            "ControlRunner started: period={:?} stale_timeout={:?}",
+            self.config.period,
+            self.config.stale_timeout
+        );
+
+        // Sentinel so the monitor also checks the first state.
+        let mut last_seq = u64::MAX;
+        let result = self.control_loop(&mut last_seq);
+        self.shutdown();
+        result
+    }
+
+    fn control_loop(&mut self, last_seq: &mut u64) -> Result<StopReason> {
+        let mut next_tick = Instant::now();
+        let mut last_tick = next_tick;
+        loop {
+            if self.stop.is_stopped() {
+                return Ok(StopReason::Requested);
+            }
+
+            let now = Instant::now();
+            let Some((state, at, seq)) = self.snapshot() else {
+                return Ok(StopReason::StaleState(Duration::MAX));
+            };
+            let age = now.saturating_duration_since(at);
+            if age > self.config.stale_timeout {
+                log::error!("ControlRunner: state is {:?} old, stopping", age);
+                return Ok(StopReason::StaleState(age));
+            }
+
+            if let Some(monitor) = self.monitor.as_mut() {
+                if seq != *last_seq {
+                    monitor.check_low_state(&state);
+                }
+                monitor.check_connection(now);
+                if monitor.tripped() {
+                    return Ok(StopReason::Fault(monitor.faults().to_vec()));
+                }
+            }
+            *last_seq = seq;
+
+            let dt = now.saturating_duration_since(last_tick).as_secs_f32();
+            last_tick = now;
+            self.controller.compute(&state, &mut self.cmd, dt);
+            self.publish()?;
+
+            next_tick += self.config.period;
+            let now = Instant::now();
+            if next_tick > now {
+                std::thread::sleep(next_tick - now);
+            } else {
+                log::debug!("ControlRunner: tick overran by {:?}", now - next_tick);
+                next_tick = now;
+            }
+        }
+    }
+
+    /// Run `on_stop` and publish damping for `shutdown_duration`.
+    fn shutdown(&mut self) {
+        self.running = false;
+        self.controller.on_stop();
+        self.damp();
+    }
+
+    fn damp(&mut self) {
+        self.cmd.set_damping(self.config.damping_kd);
+        let end = Instant::now() + self.config.shutdown_duration;
+        loop {
+            if let Err(e) = self.publish() {
+                log::error!("ControlRunner: failed to publish damping: {}", e);
+                break;
+            }
+            if Instant::now() >= end {
+                break;
+            }
+            std::thread::sleep(self.config.period);
+        }
+        log::info!("ControlRunner stopped, motors damped");
+    }
+}
+
+impl<S, C, K> Drop for ControlRunner<S, C, K>
+where
+    S: Clone + Send + LowStateView + 'static,
+    C: Crc + DampingCommand,
+    K: Controller<S, C>,
+{
+    fn drop(&mut self) {
+        if self.running {
+            self.running = false;
+            if std::thread::panicking() {
+                log::error!("ControlRunner: control loop panicked, damping");
+            }
+            // Damp before `on_stop`: a panic in it while unwinding aborts.
+            self.damp();
+            self.controller.on_stop();
+        }
+        self.subscriber.close_channel();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+
+    use super::*;
+    use crate::idl::go2::{LowCmd, LowState};
+
+    /// Go2 command that records every copy the runner stamps for publishing.
+    struct Recorded {
+        cmd: LowCmd,
+        log: Arc<Mutex<Vec<LowCmd>>>,
+    }
+
+    impl Crc for Recorded {
+        fn compute_crc(&self) -> u32 {
+            self.cmd.compute_crc()
+        }
+
+        fn stamp_crc(&mut self) {
+            self.cmd.stamp_crc();
+            self.log.lock().unwrap().push(self.cmd.clone());
+        }
+    }
+
+    impl DampingCommand for Recorded {
+        fn set_damping(&mut self, kd: f32) {
+            self.cmd.set_damping(kd);
+        }
+    }
+
+    /// Holds every motor stiff; stops or panics after a number of ticks.
+    #[derive(Default)]
+    struct Stiff {
+        ticks: usize,
+        stop_after: Option<(usize, StopHandle)>,
+        panic_after: Option<usize>,
+        stops: Arc<AtomicUsize>,
+    }
+
+    impl Controller<LowState, Recorded> for Stiff {
+        fn compute(&mut self, _state: &LowState, cmd: &mut Recorded, _dt: f32) {
+            self.ticks += 1;
+            for motor in cmd.cmd.motor_cmd.iter_mut() {
+                motor.kp = 40.0;
+                motor.kd = 1.0;
+            }
+            if let Some((n, stop)) = &self.stop_after
+                && self.ticks >= *n
+            {
+                stop.stop();
+            }
+            if self.panic_after.is_some_and(|n| self.ticks >= n) {
+                panic!("controller failure");
+            }
+        }
+
+        fn on_stop(&mut self) {
+            self.stops.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn config() -> RunnerConfig {
+        RunnerConfig {
+            cmd_topic: "rt/lowcmd_runner_test".to_string(),
+            state_topic: "rt/lowstate_runner_test".to_string(),
+            period: Duration::from_millis(1),
+            stale_timeout: Duration::from_millis(20),
+            startup_timeout: Duration::from_millis(50),
+            damping_kd: 3.0,
+            shutdown_duration: Duration::from_millis(5),
+        }
+    }
+
+    fn upright() -> LowState {
+        let mut state = LowState::default();
+        state.imu_state.quaternion = [1.0, 0.0, 0.0, 0.0];
+        state.bms_state.soc = 80;
+        state
+    }
+
+    type Runner = ControlRunner<LowState, Recorded, Stiff>;
+
+    fn runner(controller: Stiff) -> (Runner, Arc<Mutex<Vec<LowCmd>>>) {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let cmd = Recorded {
+            cmd: LowCmd::default(),
+            log: Arc::clone(&log),
+        };
+        (ControlRunner::new(controller, cmd, config()).unwrap(), log)
+    }
+
+    fn feed(runner: &Runner, state: LowState) {
+        *runner.latest.lock().unwrap() = Some(Received {
+            state,
+            at: Instant::now(),
+            seq: 0,
+        });
+    }
+
+    fn assert_damped(log: &Mutex<Vec<LowCmd>>) {
+        let log = log.lock().unwrap();
+        assert!(log.iter().any(|c| c.motor_cmd[0].kp == 40.0), "controller never published");
+        let last = log.last().unwrap();
+        assert!(last.motor_cmd.iter().all(|m| m.kp == 0.0 && m.kd == 3.0));
+    }
+
+    #[test]
+    fn stops_on_stale_state() {
+        let stops = Arc::new(AtomicUsize::new(0));
+        let (mut runner, log) = runner(Stiff {
+            stops: Arc::clone(&stops),
+            ..Default::default()
+        });
+        feed(&runner, upright());
+
+        let reason = runner.run().unwrap();
+        assert!(matches!(reason, StopReason::StaleState(age) if age > Duration::from_millis(20)));
+        drop(runner);
+        assert_eq!(stops.load(Ordering::SeqCst), 1);
+        assert_damped(&log);
+    }
+
+    #[test]
+    fn stops_on_request() {
+        let stops = Arc::new(AtomicUsize::new(0));
+        let stop = StopHandle::default();
+        let (runner, log) = runner(Stiff {
+            stop_after: Some((3, stop.clone())),
+            stops: Arc::clone(&stops),
+            ..Default::default()
+        });
+        let mut runner = runner.with_stop_handle(stop);
+        feed(&runner, upright());
+
+        assert_eq!(runner.run().unwrap(), StopReason::Requested);
+        assert_eq!(runner.controller().ticks, 3);
+        drop(runner);
+        assert_eq!(stops.load(Ordering::SeqCst), 1);
+        assert_damped(&log);
+    }
+
+    #[test]
+    fn stops_on_monitor_fault() {
+        let stops = Arc::new(AtomicUsize::new(0));
+        let (runner, log) = runner(Stiff {
+            stops: Arc::clone(&stops),
+            stop_after: Some((1, StopHandle::default())),
+            ..Default::default()
+        });
+        let mut runner = runner.with_monitor(SafetyMonitor::default());
+        let mut state = upright();
+        state.imu_state.quaternion = [0.0, 1.0, 0.0, 0.0];
+        feed(&runner, state);
+
+        // The fault is caught before the first compute.
+        let StopReason::Fault(faults) = runner.run().unwrap() else {
+            panic!("expected a fault");
+        };
+        assert!(!faults.is_empty());
+        assert_eq!(runner.controller().ticks, 0);
+        drop(runner);
+        assert_eq!(stops.load(Ordering::SeqCst), 1);
+        let last = log.lock().unwrap().last().unwrap().clone();
+        assert!(last.motor_cmd.iter().all(|m| m.kp == 0.0 && m.kd == 3.0));
+    }
+
+    #[test]
+    fn drop_damps_after_panic() {
+        let stops = Arc::new(AtomicUsize::new(0));
+        let (mut runner, log) = runner(Stiff {
+            panic_after: Some(2),
+            stops: Arc::clone(&stops),
+            ..Default::default()
+        });
+        feed(&runner, upright());
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(move || runner.run()));
+        assert!(result.is_err());
+        assert_eq!(stops.load(Ordering::SeqCst), 1);
+        assert_damped(&log);
+    }
+}
@@ -0,0 +1,408 @@
+//! StateMachine - DAMPING → STAND → CTRL low-level control for Go2.
+//!
+//! Port of the `SimpleStateMachine` example. The machine owns the `rt/lowcmd`
+//! publisher and `rt/lowstate` subscriber and builds one `LowCmd` per
+//! [`StateMachine::step`]:
+//!
+//! - **Damping**: zero stiffness, damping only.
+//! - **Stand**: PD towards `stand_pos`; kp/kd ramp up by
+//!   `delta_pd` per step, starting at `pd_ratio_init`.
+//! - **Ctrl**: a [`Controller`] fills the leg commands, starting from the
+//!   stand pose and gains; its gains are blended in from the stand gains at
+//!   the same rate.
+//!
+//! CTRL is refused until the stand gains are fully ramped and every joint
+//! is within `stand_tolerance` of `stand_pos`. The controller's `on_start`
+//! runs when CTRL is entered and `on_stop` when it is left.
+
+use std::f32::consts::FRAC_PI_2;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use crate::channel::{ChannelFactory, ChannelPublisher, ChannelSubscriber};
+use crate::error::Result;
+use crate::idl::crc::Crc;
+use crate::idl::go2::{LowCmd, LowState};
+use crate::safety::monitors::bad_orientation;
+use super::runner::Controller;
+
+/// Number of actuated Go2 leg joints.
+pub const GO2_NUM_JOINTS: usize = 12;
+
+/// Position target that tells a Go2 motor to hold no position.
+pub const POS_STOP_F: f32 = 2.146e9;
+/// Velocity target that tells a Go2 motor to hold no velocity.
+pub const VEL_STOP_F: f32 = 16000.0;
+
+pub const TOPIC_LOWCMD: &str = "rt/lowcmd";
+pub const TOPIC_LOWSTATE: &str = "rt/lowstate";
+
+/// A Go2 `LowCmd` with the frame header set and every motor in servo mode
+/// with no position or velocity target.
+pub fn servo_low_cmd() -> LowCmd {
+    let mut cmd = LowCmd {
+        head: [0xFE, 0xEF],
+        level_flag: 0xFF,
+        ..Default::default()
+    };
+    for motor in cmd.motor_cmd.iter_mut() {
+        motor.mode = 0x01;
+        motor.q = POS_STOP_F;
+        motor.dq = VEL_STOP_F;
+    }
+    cmd
+}
+
+/// Control phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Phase {
+    Damping,
+    Stand,
+    Ctrl,
+}
+
+/// Gains and ramp rates for the built-in phases.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StateMachineConfig {
+    /// Joint positions to stand at before CTRL starts.
+    pub stand_pos: [f32; GO2_NUM_JOINTS],
+    /// Stiffness at the end of the stand ramp.
+    pub stand_kp: f32,
+    /// Damping at the end of the stand ramp.
+    pub stand_kd: f32,
+    /// Damping used in the DAMPING phase.
+    pub damping_kd: f32,
+    /// Gain ratio when STAND is entered from DAMPING.
+    pub pd_ratio_init: f32,
+    /// Gain ratio change per step.
+    pub delta_pd: f32,
+    /// Gain ratio above which the stand counts as ramped.
+    pub ctrl_min_ratio: f32,
+    /// Max joint error to `stand_pos` (rad) for the stand to count as
+    /// converged.
+    pub stand_tolerance: f32,
+}
+
+impl Default for StateMachineConfig {
+    fn default() -> Self {
+        Self {
+            stand_pos: [0.0, 0.9, -1.8, 0.0, 0.9, -1.8, 0.0, 0.9, -1.8, 0.0, 0.9, -1.8],
+            stand_kp: 40.0,
+            stand_kd: 1.0,
+            damping_kd: 2.0,
+            pd_ratio_init: 0.1,
+            delta_pd: 0.005,
+            ctrl_min_ratio: 0.95,
+            stand_tolerance: 0.1,
+        }
+    }
+}
+
+/// Low-level state machine that owns the Go2 command and state channels.
+pub struct StateMachine<K: Controller<LowState, LowCmd>> {
+    controller: K,
+    config: StateMachineConfig,
+    phase: Phase,
+    pd_ratio: f32,
+    ctrl_ratio: f32,
+    last_step: Option<Instant>,
+    cmd: LowCmd,
+    latest: Arc<Mutex<Option<LowState>>>,
+    publisher: ChannelPublisher<LowCmd>,
+    subscriber: ChannelSubscriber<LowState>,
+}
+
+impl<K: Controller<LowState, LowCmd>> StateMachine<K> {
+    /// Create the channels and start in DAMPING.
+    pub fn new(controller: K, config: StateMachineConfig) -> Result<Self> {
+        let factory = ChannelFactory::instance().lock().unwrap();
+        let mut publisher: ChannelPublisher<LowCmd> = factory.create_publisher(TOPIC_LOWCMD);
+        let mut subscriber: ChannelSubscriber<LowState> = factory.create_subscriber(TOPIC_LOWSTATE);
+        drop(factory);
+
+        publisher.init_channel()?;
+        let latest = Arc::new(Mutex::new(None));
+        {
+            let latest = Arc::clone(&latest);
+            subscriber.init_channel(move |state: &LowState| {
+                *latest.lock().unwrap() = Some(state.clone());
+            })?;
+        }
+
+        Ok(Self {
+            controller,
+            config,
+            phase: Phase::Damping,
+            pd_ratio: 0.0,
+            ctrl_ratio: 0.0,
+            last_step: None,
+            cmd: servo_low_cmd(),
+            latest,
+            publisher,
+            subscriber,
+        })
+    }
+
+    /// Current phase.
+    pub fn phase(&self) -> Phase {
+        self.phase
+    }
+
+    /// Current stand gain ratio in [0, 1].
+    pub fn pd_ratio(&self) -> f32 {
+        self.pd_ratio
+    }
+
+    /// The CTRL-phase controller.
+    pub fn controller(&self) -> &K {
+        &self.controller
+    }
+
+    /// Mutable access to the CTRL-phase controller.
+    pub fn controller_mut(&mut self) -> &mut K {
+        &mut self.controller
+    }
+
+    /// Latest `LowState` received, if any.
+    pub fn latest_state(&self) -> Option<LowState> {
+        self.latest.lock().unwrap().clone()
+    }
+
+    fn leave_ctrl(&mut self) {
+        if self.phase == Phase::Ctrl {
+            self.controller.on_stop();
+        }
+    }
+
+    /// Switch to DAMPING. Always succeeds.
+    pub fn stop(&mut self) -> bool {
+        if self.phase != Phase::Damping {
+            log::info!("StateMachine: {:?} -> Damping", self.phase);
+        }
+        self.leave_ctrl();
+        self.phase = Phase::Damping;
+        self.pd_ratio = 0.0;
+        self.ctrl_ratio = 0.0;
+        true
+    }
+
+    /// Switch to STAND from DAMPING or CTRL.
+    pub fn stand(&mut self) -> bool {
+        match self.phase {
+            Phase::Damping => self.pd_ratio = self.config.pd_ratio_init,
+            Phase::Ctrl => {}
+            Phase::Stand => return false,
+        }
+        log::info!("StateMachine: {:?} -> Stand", self.phase);
+        self.leave_ctrl();
+        self.phase = Phase::Stand;
+        self.ctrl_ratio = 0.0;
+        true
+    }
+
+    /// Whether the stand gains are ramped and every joint is within
+    /// `stand_tolerance` of `stand_pos`.
+    pub fn stand_converged(&self) -> bool {
+        if self.phase != Phase::Stand || self.pd_ratio <= self.config.ctrl_min_ratio {
+            return false;
+        }
+        let Some(state) = self.latest_state() else {
+            return false;
+        };
+        state
+            .motor_state
+            .iter()
+            .zip(self.config.stand_pos)
+            .all(|(m, q)| (m.q - q).abs() <= self.config.stand_tolerance)
+    }
+
+    /// Switch to CTRL. Refused unless in STAND and the stand has converged.
+    pub fn ctrl(&mut self) -> bool {
+        if !self.stand_converged() {
+            return false;
+        }
+        if let Some(state) = self.latest_state() {
+            self.controller.on_start(&state);
+        }
+        log::info!("StateMachine: Stand -> Ctrl");
+        self.phase = Phase::Ctrl;
+        self.ctrl_ratio = 0.0;
+        true
+    }
+
+    fn ramp(ratio: f32, delta: f32) -> f32 {
+        (ratio + delta).clamp(0.0, 1.0)
+    }
+
+    /// Build and publish the command for one control step. Without a
+    /// received state the machine stays damped. In CTRL, an upside-down
+    /// body falls back to DAMPING.
+    pub fn step(&mut self) -> Result<()> {
+        let now = Instant::now();
+        let dt = self.last_step.map_or(0.0, |t| now.saturating_duration_since(t).as_secs_f32());
+        self.last_step = Some(now);
+
+        let state = self.latest_state();
+        if state.is_none() && self.phase != Phase::Damping {
+            log::warn!("StateMachine: no LowState received, damping");
+            self.stop();
+        }
+        if let (Phase::Ctrl, Some(state)) = (self.phase, &state)
+            && bad_orientation(state, FRAC_PI_2)
+        {
+            log::warn!("StateMachine: body upside down, damping");
+            self.stop();
+        }
+
+        let cfg = self.config;
+        let (kp, kd) = match self.phase {
+            Phase::Damping => (0.0, cfg.damping_kd),
+            Phase::Stand => {
+                self.pd_ratio = Self::ramp(self.pd_ratio, cfg.delta_pd);
+                (cfg.stand_kp * self.pd_ratio, cfg.stand_kd * self.pd_ratio)
+            }
+            Phase::Ctrl => (cfg.stand_kp, cfg.stand_kd),
+        };
+        for (motor, q) in self.cmd.motor_cmd.iter_mut().zip(cfg.stand_pos) {
+            motor.q = q;
+            motor.dq = 0.0;
+            motor.kp = kp;
+            motor.kd = kd;
+            motor.tau = 0.0;
+        }
+
+        if let (Phase::Ctrl, Some(state)) = (self.phase, &state) {
+            self.ctrl_ratio = Self::ramp(self.ctrl_ratio, cfg.delta_pd);
+            let r = self.ctrl_ratio;
+            self.controller.compute(state, &mut self.cmd, dt);
+            for motor in self.cmd.motor_cmd.iter_mut().take(GO2_NUM_JOINTS) {
+                motor.kp = cfg.stand_kp + (motor.kp - cfg.stand_kp) * r;
+                motor.kd = cfg.stand_kd + (motor.kd - cfg.stand_kd) * r;
+            }
+        }
+
+        self.cmd.stamp_crc();
+        self.publisher.write(&self.cmd)?;
+        Ok(())
+    }
+}
+
+impl<K: Controller<LowState, LowCmd>> Drop for StateMachine<K> {
+    fn drop(&mut self) {
+        self.subscriber.close_channel();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Probe {
+        starts: usize,
+        stops: usize,
+    }
+
+    impl Controller<LowState, LowCmd> for Probe {
+        fn on_start(&mut self, _state: &LowState) {
+            self.starts += 1;
+        }
+
+        fn compute(&mut self, _state: &LowState, cmd: &mut LowCmd, _dt: f32) {
+            for motor in cmd.motor_cmd.iter_mut().take(GO2_NUM_JOINTS) {
+                motor.kp = 60.0;
+            }
+        }
+
+        fn on_stop(&mut self) {
+            self.stops += 1;
+        }
+    }
+
+    fn standing_state(config: &StateMachineConfig) -> LowState {
+        let mut state = LowState::default();
+        state.imu_state.quaternion = [1.0, 0.0, 0.0, 0.0];
+        for (motor, q) in state.motor_state.iter_mut().zip(config.stand_pos) {
+            motor.q = q;
+        }
+        state
+    }
+
+    fn machine() -> StateMachine<Probe> {
+        let config = StateMachineConfig::default();
+        let machine = StateMachine::new(Probe::default(), config).unwrap();
+        *machine.latest.lock().unwrap() = Some(standing_state(&config));
+        machine
+    }
+
+    /// Stand and step until the gains are ramped past `ctrl_min_ratio`.
+    fn ramped() -> StateMachine<Probe> {
+        let mut machine = machine();
+        assert!(machine.stand());
+        while machine.pd_ratio() <= machine.config.ctrl_min_ratio {
+            machine.step().unwrap();
+        }
+        machine
+    }
+
+    #[test]
+    fn ctrl_refused_in_damping() {
+        let mut machine = machine();
+        assert!(!machine.ctrl());
+        assert_eq!(machine.phase(), Phase::Damping);
+    }
+
+    #[test]
+    fn stand_gains_ramp_by_delta_pd() {
+        let mut machine = machine();
+        let cfg = machine.config;
+        assert!(machine.stand());
+        assert_eq!(machine.pd_ratio(), cfg.pd_ratio_init);
+
+        machine.step().unwrap();
+        let ratio = cfg.pd_ratio_init + cfg.delta_pd;
+        assert!((machine.pd_ratio() - ratio).abs() < 1e-6);
+        assert!((machine.cmd.motor_cmd[0].kp - cfg.stand_kp * ratio).abs() < 1e-4);
+        assert!((machine.cmd.motor_cmd[0].kd - cfg.stand_kd * ratio).abs() < 1e-6);
+        assert_eq!(machine.cmd.crc, machine.cmd.compute_crc());
+
+        // Not ramped yet.
+        assert!(!machine.ctrl());
+    }
+
+    #[test]
+    fn ctrl_refused_until_joints_converge() {
+        let mut machine = ramped();
+        let mut state = machine.latest_state().unwrap();
+        state.motor_state[4].q += 2.0 * machine.config.stand_tolerance;
+        *machine.latest.lock().unwrap() = Some(state.clone());
+        assert!(!machine.ctrl());
+        assert_eq!(machine.controller().starts, 0);
+
+        state.motor_state[4].q = machine.config.stand_pos[4];
+        *machine.latest.lock().unwrap() = Some(state);
+        assert!(machine.ctrl());
+        assert_eq!(machine.phase(), Phase::Ctrl);
+        assert_eq!(machine.controller().starts, 1);
+    }
+
+    #[test]
+    fn upside_down_in_ctrl_falls_back_to_damping() {
+        let mut machine = ramped();
+        assert!(machine.ctrl());
+        machine.step().unwrap();
+        // The controller's gains are blended in from the stand gains.
+        let kp = machine.cmd.motor_cmd[0].kp;
+        assert!(kp > machine.config.stand_kp && kp < 60.0);
+
+        let mut state = machine.latest_state().unwrap();
+        state.imu_state.quaternion = [0.0, 1.0, 0.0, 0.0];
+        *machine.latest.lock().unwrap() = Some(state);
+        machine.step().unwrap();
+
+        assert_eq!(machine.phase(), Phase::Damping);
+        assert_eq!(machine.controller().stops, 1);
+        assert_eq!(machine.cmd.motor_cmd[0].kp, 0.0);
+        assert_eq!(machine.cmd.motor_cmd[0].kd, machine.config.damping_kd);
+    }
+}
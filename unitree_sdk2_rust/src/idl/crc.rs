@@ -0,0 +1,191 @@
+//! CRC stamping for low-level commands.
+//!
+//! The robot drops `LowCmd` messages whose `crc` field does not match a
+//! CRC-32 (polynomial 0x04C11DB7, no reflection) of the message as laid out
+//! in memory by the C++ SDK, taken over every 32-bit word before `crc`.
+//! [`Crc`] reproduces that layout, including alignment padding.
+
+use super::{go2, hg};
+
+/// CRC-32 over 32-bit words, as `crc32_core` in the C++ SDK.
+pub fn crc32_core(words: &[u32]) -> u32 {
+    const POLYNOMIAL: u32 = 0x04C1_1DB7;
+    let mut crc = 0xFFFF_FFFFu32;
+    for &data in words {
+        let mut xbit = 1u32 << 31;
+        for _ in 0..32 {
+            if crc & 0x8000_0000 != 0 {
+                crc = (crc << 1) ^ POLYNOMIAL;
+            } else {
+                crc <<= 1;
+            }
+            if data & xbit != 0 {
+                crc ^= POLYNOMIAL;
+            }
+            xbit >>= 1;
+        }
+    }
+    crc
+}
+
+/// Little-endian writer that mirrors C struct layout.
+#[derive(Default)]
+struct Layout(Vec<u8>);
+
+impl Layout {
+    fn align(&mut self, n: usize) {
+        while !self.0.len().is_multiple_of(n) {
+            self.0.push(0);
+        }
+    }
+
+    fn u8(&mut self, v: u8) {
+        self.0.push(v);
+    }
+
+    fn bytes(&mut self, v: &[u8]) {
+        self.0.extend_from_slice(v);
+    }
+
+    fn u16(&mut self, v: u16) {
+        self.align(2);
+        self.0.extend_from_slice(&v.to_le_bytes());
+    }
+
+    fn u32(&mut self, v: u32) {
+        self.align(4);
+        self.0.extend_from_slice(&v.to_le_bytes());
+    }
+
+    fn f32(&mut self, v: f32) {
+        self.u32(v.to_bits());
+    }
+
+    fn words(mut self) -> Vec<u32> {
+        self.align(4);
+        self.0
+            .chunks_exact(4)
+            .map(|c| u32::from_le_bytes([c[0], c[1], c[2], c[3]]))
+            .collect()
+    }
+}
+
+/// Messages carrying a CRC over their own contents.
+pub trait Crc {
+    /// CRC of the message, ignoring the current `crc` field.
+    fn compute_crc(&self) -> u32;
+
+    /// Set the `crc` field to [`Crc::compute_crc`].
+    fn stamp_crc(&mut self);
+}
+
+/// Bytes of a Go2 `LowCmd` before `crc`.
+fn go2_layout(cmd: &go2::LowCmd) -> Layout {
+    let mut l = Layout::default();
+    l.bytes(&cmd.head);
+    l.u8(cmd.level_flag);
+    l.u8(cmd.frame_reserve);
+    cmd.sn.iter().for_each(|&v| l.u32(v));
+    cmd.version.iter().for_each(|&v| l.u32(v));
+    l.u16(cmd.bandwidth);
+    for m in &cmd.motor_cmd {
+        l.align(4);
+        l.u8(m.mode);
+        l.f32(m.q);
+        l.f32(m.dq);
+        l.f32(m.tau);
+        l.f32(m.kp);
+        l.f32(m.kd);
+        m.reserve.iter().for_each(|&v| l.u32(v));
+    }
+    l.u8(cmd.bms_cmd.off);
+    l.bytes(&cmd.bms_cmd.reserve);
+    l.bytes(&cmd.wireless_remote);
+    l.bytes(&cmd.led);
+    l.bytes(&cmd.fan);
+    l.u8(cmd.gpio);
+    l.u32(cmd.reserve);
+    l
+}
+
+/// Bytes of an HG `LowCmd` before `crc`.
+fn hg_layout(cmd: &hg::LowCmd) -> Layout {
+    let mut l = Layout::default();
+    l.u8(cmd.mode_pr);
+    l.u8(cmd.mode_machine);
+    for m in &cmd.motor_cmd {
+        l.align(4);
+        l.u8(m.mode);
+        l.f32(m.q);
+        l.f32(m.dq);
+        l.f32(m.tau);
+        l.f32(m.kp);
+        l.f32(m.kd);
+        l.u32(m.reserve);
+    }
+    cmd.reserve.iter().for_each(|&v| l.u32(v));
+    l
+}
+
+impl Crc for go2::LowCmd {
+    fn compute_crc(&self) -> u32 {
+        crc32_core(&go2_layout(self).words())
+    }
+
+    fn stamp_crc(&mut self) {
+        self.crc = self.compute_crc();
+    }
+}
+
+impl Crc for hg::LowCmd {
+    fn compute_crc(&self) -> u32 {
+        crc32_core(&hg_layout(self).words())
+    }
+
+    fn stamp_crc(&mut self) {
+        self.crc = self.compute_crc();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crc32_core_matches_mpeg2_crc() {
+        // CRC-32/MPEG-2 of the big-endian bytes of each word.
+        assert_eq!(crc32_core(&[]), 0xFFFF_FFFF);
+        assert_eq!(crc32_core(&[0]), 0xC704_DD7B);
+        assert_eq!(crc32_core(&[0xDEAD_BEEF]), 0x81DA_1A18);
+        assert_eq!(crc32_core(&[0x3132_3334, 0x3536_3738]), 0x49E3_C2FB);
+    }
+
+    #[test]
+    fn go2_layout_covers_808_bytes() {
+        // sizeof(unitree_go::msg::dds_::LowCmd_) is 812; the CRC covers all
+        // but the trailing crc word.
+        let l = go2_layout(&go2::LowCmd::default());
+        assert_eq!(l.0.len(), 808);
+        assert_eq!(l.words().len(), 202);
+    }
+
+    #[test]
+    fn hg_layout_covers_1000_bytes() {
+        // 2 mode bytes padded to 4, 35 motors of 28 bytes, 4 reserve words.
+        let l = hg_layout(&hg::LowCmd::default());
+        assert_eq!(l.0.len(), 4 + 35 * 28 + 16);
+        assert_eq!(l.words().len(), 250);
+    }
+
+    #[test]
+    fn stamp_crc_ignores_previous_crc() {
+        let mut cmd = hg::LowCmd::default();
+        cmd.stamp_crc();
+        let first = cmd.crc;
+        cmd.stamp_crc();
+        assert_eq!(cmd.crc, first);
+
+        cmd.motor_cmd[0].q = 0.5;
+        assert_ne!(cmd.compute_crc(), first);
+    }
+}
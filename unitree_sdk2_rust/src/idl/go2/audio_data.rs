@@ -0,0 +1,9 @@
+//! AudioData IDL message.
+use serde::{Deserialize, Serialize};
+
+/// A frame of raw audio samples.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AudioData {
+    pub time_frame: u64,
+    pub data: Vec<u8>,
+}
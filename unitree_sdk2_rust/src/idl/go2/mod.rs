@@ -1,4 +1,5 @@
 //! Go2 IDL messages.
+pub mod audio_data;
 pub mod bms_cmd;
 pub mod bms_state;
 pub mod imu_state;
@@ -11,6 +12,7 @@ pub mod sport_mode_state;
 pub mod time_spec;
 pub mod wireless_controller;
 
+pub use audio_data::AudioData;
 pub use bms_cmd::BmsCmd;
 pub use bms_state::BmsState;
 pub use imu_state::IMUState;
@@ -19,6 +21,6 @@ pub use low_state::LowState;
 pub use motor_cmd::MotorCmd;
 pub use motor_state::MotorState;
 pub use path_point::PathPoint;
-pub use sport_mode_state::SportModeState;
+pub use sport_mode_state::{GaitType, SportMode, SportModeState};
 pub use time_spec::TimeSpec;
 pub use wireless_controller::WirelessController;
@@ -22,3 +22,84 @@ pub struct SportModeState {
     pub foot_speed_body: [f32; 12],
     pub path_point: [PathPoint; 10],
 }
+
+impl SportModeState {
+    /// Decoded FSM mode. Returns the raw value if it is not a known mode.
+    pub fn mode(&self) -> Result<SportMode, u8> {
+        SportMode::try_from(self.mode)
+    }
+
+    /// Decoded gait type. Returns the raw value if it is not a known gait.
+    pub fn gait_type(&self) -> Result<GaitType, u8> {
+        GaitType::try_from(self.gait_type)
+    }
+}
+
+/// Go2 sport-mode FSM state as reported in `SportModeState::mode`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum SportMode {
+    /// Idle, default stand.
+    Idle = 0,
+    BalanceStand = 1,
+    Pose = 2,
+    Locomotion = 3,
+    LieDown = 5,
+    JointLock = 6,
+    Damping = 7,
+    RecoveryStand = 8,
+    Sit = 10,
+    FrontFlip = 11,
+    FrontJump = 12,
+    FrontPounce = 13,
+}
+
+impl TryFrom<u8> for SportMode {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::Idle,
+            1 => Self::BalanceStand,
+            2 => Self::Pose,
+            3 => Self::Locomotion,
+            5 => Self::LieDown,
+            6 => Self::JointLock,
+            7 => Self::Damping,
+            8 => Self::RecoveryStand,
+            10 => Self::Sit,
+            11 => Self::FrontFlip,
+            12 => Self::FrontJump,
+            13 => Self::FrontPounce,
+            other => return Err(other),
+        })
+    }
+}
+
+/// Go2 gait type as reported in `SportModeState::gait_type`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum GaitType {
+    Idle = 0,
+    Trot = 1,
+    TrotRunning = 2,
+    ForwardClimbStair = 3,
+    ReverseClimbStair = 4,
+    Adjust = 9,
+}
+
+impl TryFrom<u8> for GaitType {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::Idle,
+            1 => Self::Trot,
+            2 => Self::TrotRunning,
+            3 => Self::ForwardClimbStair,
+            4 => Self::ReverseClimbStair,
+            9 => Self::Adjust,
+            other => return Err(other),
+        })
+    }
+}
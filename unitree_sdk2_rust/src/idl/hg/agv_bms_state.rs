@@ -0,0 +1,14 @@
+//! AgvBmsState IDL message.
+use serde::{Deserialize, Serialize};
+
+/// Battery state of the G1 wheeled base.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AgvBmsState {
+    pub software_version: String,
+    pub battery_percentage: u8,
+    pub current: i32,
+    pub temperature: [i16; 3],
+    pub docking_status: String,
+    pub is_charging: bool,
+    pub is_dc_connected: bool,
+}
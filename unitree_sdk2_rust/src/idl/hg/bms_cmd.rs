@@ -0,0 +1,20 @@
+//! BmsCmd IDL message.
+use serde::{Deserialize, Serialize};
+use serde_big_array::BigArray;
+
+/// Battery management system command.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BmsCmd {
+    pub cmd: u8,
+    #[serde(with = "BigArray")]
+    pub reserve: [u8; 40],
+}
+
+impl Default for BmsCmd {
+    fn default() -> Self {
+        Self {
+            cmd: 0,
+            reserve: [0u8; 40],
+        }
+    }
+}
@@ -0,0 +1,42 @@
+//! BmsState IDL message.
+use serde::{Deserialize, Serialize};
+use serde_big_array::BigArray;
+
+/// Battery management system state.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BmsState {
+    pub version_high: u8,
+    pub version_low: u8,
+    pub r#fn: u8,
+    #[serde(with = "BigArray")]
+    pub cell_vol: [u16; 40],
+    pub bmsvoltage: [u32; 3],
+    pub current: i32,
+    pub soc: u8,
+    pub soh: u8,
+    pub temperature: [i16; 12],
+    pub cycle: u16,
+    pub manufacturer_date: u16,
+    pub bmsstate: [u32; 5],
+    pub reserve: [u32; 3],
+}
+
+impl Default for BmsState {
+    fn default() -> Self {
+        Self {
+            version_high: 0,
+            version_low: 0,
+            r#fn: 0,
+            cell_vol: [0u16; 40],
+            bmsvoltage: Default::default(),
+            current: 0,
+            soc: 0,
+            soh: 0,
+            temperature: Default::default(),
+            cycle: 0,
+            manufacturer_date: 0,
+            bmsstate: Default::default(),
+            reserve: Default::default(),
+        }
+    }
+}
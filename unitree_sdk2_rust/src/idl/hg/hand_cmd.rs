@@ -0,0 +1,10 @@
+//! HandCmd IDL message.
+use serde::{Deserialize, Serialize};
+use super::MotorCmd;
+
+/// Dexterous hand command.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct HandCmd {
+    pub motor_cmd: Vec<MotorCmd>,
+    pub reserve: [u32; 4],
+}
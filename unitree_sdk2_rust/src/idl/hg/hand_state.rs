@@ -0,0 +1,17 @@
+//! HandState IDL message.
+use serde::{Deserialize, Serialize};
+use super::{IMUState, MotorState, PressSensorState};
+
+/// Dexterous hand state.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct HandState {
+    pub motor_state: Vec<MotorState>,
+    pub press_sensor_state: Vec<PressSensorState>,
+    pub imu_state: IMUState,
+    pub power_v: f32,
+    pub power_a: f32,
+    pub system_v: f32,
+    pub device_v: f32,
+    pub error: [u32; 2],
+    pub reserve: [u32; 2],
+}
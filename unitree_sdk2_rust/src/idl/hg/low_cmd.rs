@@ -0,0 +1,27 @@
+//! LowCmd IDL message.
+use serde::{Deserialize, Serialize};
+use serde_big_array::BigArray;
+use super::MotorCmd;
+
+/// Low-level command sent to the robot.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LowCmd {
+    pub mode_pr: u8,
+    pub mode_machine: u8,
+    #[serde(with = "BigArray")]
+    pub motor_cmd: [MotorCmd; 35],
+    pub reserve: [u32; 4],
+    pub crc: u32,
+}
+
+impl Default for LowCmd {
+    fn default() -> Self {
+        Self {
+            mode_pr: 0,
+            mode_machine: 0,
+            motor_cmd: std::array::from_fn(|_| MotorCmd::default()),
+            reserve: Default::default(),
+            crc: 0,
+        }
+    }
+}
@@ -0,0 +1,36 @@
+//! LowState IDL message.
+use serde::{Deserialize, Serialize};
+use serde_big_array::BigArray;
+use super::{IMUState, MotorState};
+
+/// Low-level state received from the robot.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LowState {
+    pub version: [u32; 2],
+    pub mode_pr: u8,
+    pub mode_machine: u8,
+    pub tick: u32,
+    pub imu_state: IMUState,
+    #[serde(with = "BigArray")]
+    pub motor_state: [MotorState; 35],
+    #[serde(with = "BigArray")]
+    pub wireless_remote: [u8; 40],
+    pub reserve: [u32; 4],
+    pub crc: u32,
+}
+
+impl Default for LowState {
+    fn default() -> Self {
+        Self {
+            version: Default::default(),
+            mode_pr: 0,
+            mode_machine: 0,
+            tick: 0,
+            imu_state: IMUState::default(),
+            motor_state: std::array::from_fn(|_| MotorState::default()),
+            wireless_remote: [0u8; 40],
+            reserve: Default::default(),
+            crc: 0,
+        }
+    }
+}
@@ -0,0 +1,11 @@
+//! MainBoardState IDL message.
+use serde::{Deserialize, Serialize};
+
+/// Main board fan, temperature and status readings.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MainBoardState {
+    pub fan_state: [u16; 6],
+    pub temperature: [i16; 6],
+    pub value: [f32; 6],
+    pub state: [u32; 6],
+}
@@ -0,0 +1,28 @@
+//! Unitree HG IDL messages, used by the G1 and H1 humanoids.
+pub mod agv_bms_state;
+pub mod bms_cmd;
+pub mod bms_state;
+pub mod hand_cmd;
+pub mod hand_state;
+pub mod imu_state;
+pub mod low_cmd;
+pub mod low_state;
+pub mod main_board_state;
+pub mod motor_cmd;
+pub mod motor_state;
+pub mod press_sensor_state;
+pub mod sport_mode_state;
+
+pub use agv_bms_state::AgvBmsState;
+pub use bms_cmd::BmsCmd;
+pub use bms_state::BmsState;
+pub use hand_cmd::HandCmd;
+pub use hand_state::HandState;
+pub use imu_state::IMUState;
+pub use low_cmd::LowCmd;
+pub use low_state::LowState;
+pub use main_board_state::MainBoardState;
+pub use motor_cmd::MotorCmd;
+pub use motor_state::MotorState;
+pub use press_sensor_state::PressSensorState;
+pub use sport_mode_state::SportModeState;
@@ -0,0 +1,17 @@
+//! MotorState IDL message.
+use serde::{Deserialize, Serialize};
+
+/// Motor state reported by the robot.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MotorState {
+    pub mode: u8,
+    pub q: f32,
+    pub dq: f32,
+    pub ddq: f32,
+    pub tau_est: f32,
+    pub temperature: [i16; 2],
+    pub vol: f32,
+    pub sensor: [u32; 2],
+    pub motorstate: u32,
+    pub reserve: [u32; 4],
+}
@@ -0,0 +1,11 @@
+//! PressSensorState IDL message.
+use serde::{Deserialize, Serialize};
+
+/// Tactile pressure sensor readings.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PressSensorState {
+    pub pressure: [f32; 12],
+    pub temperature: [f32; 12],
+    pub lost: u32,
+    pub reserve: u32,
+}
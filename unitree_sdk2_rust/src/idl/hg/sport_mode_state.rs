@@ -0,0 +1,11 @@
+//! SportModeState IDL message.
+use serde::{Deserialize, Serialize};
+
+/// Humanoid locomotion FSM state.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SportModeState {
+    pub fsm_id: u32,
+    pub fsm_mode: u32,
+    pub task_id: u32,
+    pub task_time: f32,
+}
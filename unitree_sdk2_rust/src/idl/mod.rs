@@ -1,2 +1,5 @@
 //! IDL message definitions.
+pub mod crc;
 pub mod go2;
+pub mod hg;
+pub mod ros2;
@@ -0,0 +1,4 @@
+//! ROS 2 standard IDL messages used by Unitree services.
+pub mod string_msg;
+
+pub use string_msg::StringMsg;
@@ -0,0 +1,8 @@
+//! std_msgs String IDL message.
+use serde::{Deserialize, Serialize};
+
+/// A single string payload (`std_msgs::msg::String`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StringMsg {
+    pub data: String,
+}
@@ -2,4 +2,10 @@
 pub mod error;
 pub mod idl;
 pub mod channel;
+pub mod rpc;
 pub mod robot;
+pub mod audio;
+pub mod camera;
+pub mod control;
+pub mod motion;
+pub mod safety;
@@ -0,0 +1,6 @@
+//! Keyframe motion sequences and playback.
+pub mod player;
+pub mod sequence;
+
+pub use player::{HoldGains, MotionPlayer, PlaybackHandle, PlaybackState, PlayerConfig};
+pub use sequence::{Interpolation, Keyframe, MotionSequence, SequenceError};
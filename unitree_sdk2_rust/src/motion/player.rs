@@ -0,0 +1,280 @@
+//! MotionPlayer - streams a [`MotionSequence`] to an HG robot.
+//!
+//! The player is a [`Controller`] for HG `LowState`/`LowCmd`, so it runs
+//! inside a [`ControlRunner`] and inherits its fixed rate, CRC stamping,
+//! stale-state detection and damping on exit. Playback starts from the pose
+//! the robot is in when the first state arrives and can be paused, resumed
+//! or aborted from any thread through a [`PlaybackHandle`]. Motors outside
+//! the sequence hold their starting pose with [`HoldGains`].
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use crate::control::{ControlRunner, Controller, RunnerConfig, StopHandle};
+use crate::error::Result;
+use crate::idl::hg::{LowCmd, LowState};
+use super::sequence::{MotionSequence, HG_NUM_MOTOR};
+
+/// Playback state shared between the player and its handles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum PlaybackState {
+    Playing = 0,
+    /// Holding the current setpoint; time does not advance.
+    Paused = 1,
+    /// The sequence ended and playback stopped.
+    Finished = 2,
+    /// Stopped before the end.
+    Aborted = 3,
+}
+
+impl TryFrom<u8> for PlaybackState {
+    type Error = u8;
+
+    fn try_from(value: u8) -> std::result::Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::Playing,
+            1 => Self::Paused,
+            2 => Self::Finished,
+            3 => Self::Aborted,
+            other => return Err(other),
+        })
+    }
+}
+
+/// Controls a running [`MotionPlayer`]. Cloneable and `Send`.
+#[derive(Debug, Clone, Default)]
+pub struct PlaybackHandle {
+    state: Arc<AtomicU8>,
+    stop: StopHandle,
+}
+
+impl PlaybackHandle {
+    fn transition(&self, from: PlaybackState, to: PlaybackState) -> bool {
+        self.state
+            .compare_exchange(from as u8, to as u8, Ordering::SeqCst, Ordering::SeqCst)
+            .is_ok()
+    }
+
+    /// Current playback state.
+    pub fn state(&self) -> PlaybackState {
+        PlaybackState::try_from(self.state.load(Ordering::SeqCst)).unwrap_or(PlaybackState::Aborted)
+    }
+
+    /// Hold the current setpoint. Returns false unless playing.
+    pub fn pause(&self) -> bool {
+        self.transition(PlaybackState::Playing, PlaybackState::Paused)
+    }
+
+    /// Continue after [`PlaybackHandle::pause`]. Returns false unless paused.
+    pub fn resume(&self) -> bool {
+        self.transition(PlaybackState::Paused, PlaybackState::Playing)
+    }
+
+    /// Stop playback; the runner damps the motors. Returns false if
+    /// playback had already ended.
+    pub fn abort(&self) -> bool {
+        let aborted = self.transition(PlaybackState::Playing, PlaybackState::Aborted)
+            || self.transition(PlaybackState::Paused, PlaybackState::Aborted);
+        self.stop.stop();
+        aborted
+    }
+
+    /// The stop handle of the runner this playback drives.
+    pub fn stop_handle(&self) -> StopHandle {
+        self.stop.clone()
+    }
+}
+
+/// Playback options.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlayerConfig {
+    /// Restart from the first keyframe after the hold instead of stopping.
+    pub looping: bool,
+    /// Seconds to hold the last keyframe before stopping or looping.
+    pub hold: f32,
+}
+
+impl Default for PlayerConfig {
+    fn default() -> Self {
+        Self {
+            looping: false,
+            hold: 1.0,
+        }
+    }
+}
+
+/// Gains holding the motors outside the sequence at the pose they are in
+/// when playback starts. Slots with zero gains stay disabled.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HoldGains {
+    pub kp: [f32; HG_NUM_MOTOR],
+    pub kd: [f32; HG_NUM_MOTOR],
+}
+
+impl HoldGains {
+    /// The same gains on every slot.
+    pub fn uniform(kp: f32, kd: f32) -> Self {
+        Self {
+            kp: [kp; HG_NUM_MOTOR],
+            kd: [kd; HG_NUM_MOTOR],
+        }
+    }
+
+    /// Gearbox gains of the 29 G1 motors, as in `g1_dual_arm_example.cpp`:
+    /// kp 100 on the knees and 40 elsewhere, kd 1.
+    pub fn g1_gearbox() -> Self {
+        let mut gains = Self::uniform(0.0, 0.0);
+        for i in 0..29 {
+            gains.kp[i] = if i == 3 || i == 9 { 100.0 } else { 40.0 };
+            gains.kd[i] = 1.0;
+        }
+        gains
+    }
+}
+
+/// Plays a [`MotionSequence`] as a [`Controller`].
+pub struct MotionPlayer {
+    sequence: MotionSequence,
+    config: PlayerConfig,
+    hold: HoldGains,
+    handle: PlaybackHandle,
+    start: Vec<f32>,
+    hold_pose: [f32; HG_NUM_MOTOR],
+    elapsed: f32,
+}
+
+impl MotionPlayer {
+    /// Create a player, ready to play from the start. Motors outside the
+    /// sequence are held with `hold`.
+    pub fn new(sequence: MotionSequence, config: PlayerConfig, hold: HoldGains) -> Self {
+        let start = sequence.final_pose().to_vec();
+        Self {
+            sequence,
+            config,
+            hold,
+            handle: PlaybackHandle::default(),
+            start,
+            hold_pose: [0.0; HG_NUM_MOTOR],
+            elapsed: 0.0,
+        }
+    }
+
+    /// Handle for pausing, resuming and aborting playback.
+    pub fn handle(&self) -> PlaybackHandle {
+        self.handle.clone()
+    }
+
+    /// The sequence being played.
+    pub fn sequence(&self) -> &MotionSequence {
+        &self.sequence
+    }
+
+    /// Playback time in seconds since the current pass started.
+    pub fn elapsed(&self) -> f32 {
+        self.elapsed
+    }
+
+    /// Wrap the player in a [`ControlRunner`] publishing HG `LowCmd`s in PR
+    /// mode. The runner stops when playback finishes or is aborted.
+    pub fn into_runner(self, config: RunnerConfig) -> Result<ControlRunner<LowState, LowCmd, Self>> {
+        let stop = self.handle.stop_handle();
+        Ok(ControlRunner::new(self, LowCmd::default(), config)?.with_stop_handle(stop))
+    }
+}
+
+impl Controller<LowState, LowCmd> for MotionPlayer {
+    fn on_start(&mut self, state: &LowState) {
+        self.start = self
+            .sequence
+            .joints()
+            .iter()
+            .map(|&j| state.motor_state[j].q)
+            .collect();
+        for (q, motor) in self.hold_pose.iter_mut().zip(&state.motor_state) {
+            *q = motor.q;
+        }
+        self.elapsed = 0.0;
+        log::info!(
+            "MotionPlayer: {} keyframes over {:.3} s on {} joints",
+            self.sequence.len(),
+            self.sequence.duration(),
+            self.sequence.joints().len()
+        );
+    }
+
+    fn compute(&mut self, state: &LowState, cmd: &mut LowCmd, dt: f32) {
+        cmd.mode_machine = state.mode_machine;
+
+        if self.handle.state() == PlaybackState::Playing {
+            self.elapsed += dt;
+            let end = self.sequence.duration() + self.config.hold;
+            if self.elapsed >= end {
+                if self.config.looping {
+                    log::info!("MotionPlayer: looping");
+                    self.start = self.sequence.final_pose().to_vec();
+                    self.elapsed = 0.0;
+                } else if self.handle.transition(PlaybackState::Playing, PlaybackState::Finished) {
+                    log::info!("MotionPlayer: finished");
+                    self.handle.stop.stop();
+                }
+            }
+        }
+
+        for (i, m) in cmd.motor_cmd.iter_mut().enumerate() {
+            let (kp, kd) = (self.hold.kp[i], self.hold.kd[i]);
+            m.mode = u8::from(kp != 0.0 || kd != 0.0);
+            m.q = self.hold_pose[i];
+            m.dq = 0.0;
+            m.tau = 0.0;
+            m.kp = kp;
+            m.kd = kd;
+        }
+        if let Err(e) = self.sequence.apply(self.elapsed, &self.start, cmd) {
+            log::error!("MotionPlayer: {}", e);
+            self.handle.abort();
+        }
+    }
+
+    fn on_stop(&mut self) {
+        if self.handle.transition(PlaybackState::Playing, PlaybackState::Aborted)
+            || self.handle.transition(PlaybackState::Paused, PlaybackState::Aborted)
+        {
+            log::warn!("MotionPlayer: aborted at {:.3} s", self.elapsed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::motion::sequence::{Interpolation, Keyframe};
+
+    #[test]
+    fn holds_motors_outside_the_sequence() {
+        let keyframes = vec![Keyframe {
+            duration: 1.0,
+            q: vec![0.5],
+            kp: None,
+            kd: None,
+        }];
+        let sequence =
+            MotionSequence::new(vec![15], vec![40.0], vec![1.0], Interpolation::Linear, keyframes).unwrap();
+        let mut player = MotionPlayer::new(sequence, PlayerConfig::default(), HoldGains::g1_gearbox());
+
+        let mut state = LowState::default();
+        state.motor_state[3].q = 1.2;
+        player.on_start(&state);
+        let mut cmd = LowCmd::default();
+        player.compute(&state, &mut cmd, 0.002);
+
+        let knee = &cmd.motor_cmd[3];
+        assert_eq!(knee.mode, 1);
+        assert_eq!(knee.q, 1.2);
+        assert_eq!((knee.kp, knee.kd), (100.0, 1.0));
+        assert_eq!(cmd.motor_cmd[15].mode, 1);
+        assert_eq!(cmd.motor_cmd[15].kp, 40.0);
+        // Slots without a G1 motor stay disabled.
+        assert_eq!(cmd.motor_cmd[30].mode, 0);
+    }
+}
@@ -0,0 +1,548 @@
+//! Keyframe motion sequences for HG (humanoid) low-level control.
+//!
+//! A sequence lists the HG motor indices it drives, default gains, and a
+//! series of keyframes. Each keyframe holds joint targets and the time to
+//! reach them from the previous keyframe (or, for the first one, from the
+//! pose the robot is in when playback starts). Keyframes may override the
+//! gains; gains are interpolated along with the targets.
+//!
+//! Sequences are loaded at runtime from JSON:
+//!
+//! ```json
+//! {
+//!   "joints": [15, 16, 17, 18],
+//!   "kp": 40.0,
+//!   "kd": [1.0, 1.0, 1.0, 1.0],
+//!   "interpolation": "smooth",
+//!   "keyframes": [
+//!     { "duration": 2.0, "q": [0.0, 0.3, 0.0, 1.2] },
+//!     { "duration": 1.0, "q": [-0.5, 0.3, 0.0, 0.6], "kp": [60.0, 60.0, 40.0, 40.0] }
+//!   ]
+//! }
+//! ```
+//!
+//! `kp`/`kd` are either one value for every joint or one value per joint.
+//! Choreonoid `.seq` body motions, as used by the C++ dual-arm example, can
+//! be imported with [`MotionSequence::from_choreonoid_seq`].
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::idl::hg::LowCmd;
+
+/// Number of motor slots in an HG `LowCmd`.
+pub const HG_NUM_MOTOR: usize = 35;
+
+/// Error returned when loading or building a sequence.
+#[derive(Debug, Error)]
+pub enum SequenceError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("Invalid sequence: {0}")]
+    Invalid(String),
+}
+
+fn invalid(msg: impl Into<String>) -> SequenceError {
+    SequenceError::Invalid(msg.into())
+}
+
+/// How targets and gains move between keyframes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Interpolation {
+    /// Constant velocity.
+    #[default]
+    Linear,
+    /// Smoothstep: zero velocity at every keyframe.
+    Smooth,
+}
+
+impl Interpolation {
+    /// Map segment progress `s` in [0, 1] to the interpolation weight.
+    pub fn shape(self, s: f32) -> f32 {
+        match self {
+            Interpolation::Linear => s,
+            Interpolation::Smooth => s * s * (3.0 - 2.0 * s),
+        }
+    }
+}
+
+/// One keyframe as written in a sequence file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Keyframe {
+    /// Seconds to reach this keyframe from the previous one.
+    pub duration: f32,
+    /// Joint targets (rad), one per sequence joint.
+    pub q: Vec<f32>,
+    /// Stiffness override, one per sequence joint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kp: Option<Vec<f32>>,
+    /// Damping override, one per sequence joint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kd: Option<Vec<f32>>,
+}
+
+/// Gains given either for every joint at once or per joint.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+enum Gains {
+    Uniform(f32),
+    PerJoint(Vec<f32>),
+}
+
+impl Gains {
+    fn resolve(self, n: usize, name: &str) -> Result<Vec<f32>, SequenceError> {
+        match self {
+            Gains::Uniform(v) => Ok(vec![v; n]),
+            Gains::PerJoint(v) if v.len() == n => Ok(v),
+            Gains::PerJoint(v) => Err(invalid(format!(
+                "{name} has {} values for {n} joints",
+                v.len()
+            ))),
+        }
+    }
+}
+
+/// Sequence file layout.
+#[derive(Debug, Deserialize)]
+struct SequenceFile {
+    joints: Vec<usize>,
+    kp: Gains,
+    kd: Gains,
+    #[serde(default)]
+    interpolation: Interpolation,
+    keyframes: Vec<Keyframe>,
+}
+
+/// A keyframe with its gains resolved and its absolute end time.
+#[derive(Debug, Clone, PartialEq)]
+struct Frame {
+    end: f32,
+    q: Vec<f32>,
+    kp: Vec<f32>,
+    kd: Vec<f32>,
+}
+
+/// A validated keyframe sequence.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MotionSequence {
+    joints: Vec<usize>,
+    interpolation: Interpolation,
+    frames: Vec<Frame>,
+}
+
+fn check_finite(values: &[f32], what: &str, index: usize) -> Result<(), SequenceError> {
+    if values.iter().all(|v| v.is_finite()) {
+        Ok(())
+    } else {
+        Err(invalid(format!("keyframe {index}: {what} is not finite")))
+    }
+}
+
+impl MotionSequence {
+    /// Build a sequence. `kp`/`kd` are the default gains for keyframes that
+    /// do not override them.
+    pub fn new(
+        joints: Vec<usize>,
+        kp: Vec<f32>,
+        kd: Vec<f32>,
+        interpolation: Interpolation,
+        keyframes: Vec<Keyframe>,
+    ) -> Result<Self, SequenceError> {
+        let n = joints.len();
+        if n == 0 {
+            return Err(invalid("no joints"));
+        }
+        for (i, &joint) in joints.iter().enumerate() {
+            if joint >= HG_NUM_MOTOR {
+                return Err(invalid(format!("joint {joint} out of range")));
+            }
+            if joints[..i].contains(&joint) {
+                return Err(invalid(format!("joint {joint} listed twice")));
+            }
+        }
+        if kp.len() != n || kd.len() != n {
+            return Err(invalid(format!("default gains must have {n} values")));
+        }
+        if keyframes.is_empty() {
+            return Err(invalid("no keyframes"));
+        }
+
+        let mut end = 0.0;
+        let mut frames = Vec::with_capacity(keyframes.len());
+        for (index, keyframe) in keyframes.into_iter().enumerate() {
+            if !keyframe.duration.is_finite() || keyframe.duration < 0.0 {
+                return Err(invalid(format!("keyframe {index}: bad duration {}", keyframe.duration)));
+            }
+            let frame_kp = keyframe.kp.unwrap_or_else(|| kp.clone());
+            let frame_kd = keyframe.kd.unwrap_or_else(|| kd.clone());
+            for (what, values) in [("q", &keyframe.q), ("kp", &frame_kp), ("kd", &frame_kd)] {
+                if values.len() != n {
+                    return Err(invalid(format!(
+                        "keyframe {index}: {what} has {} values for {n} joints",
+                        values.len()
+                    )));
+                }
+                check_finite(values, what, index)?;
+            }
+            end += keyframe.duration;
+            frames.push(Frame {
+                end,
+                q: keyframe.q,
+                kp: frame_kp,
+                kd: frame_kd,
+            });
+        }
+
+        Ok(Self {
+            joints,
+            interpolation,
+            frames,
+        })
+    }
+
+    /// Parse a JSON sequence.
+    pub fn from_json(text: &str) -> Result<Self, SequenceError> {
+        let file: SequenceFile = serde_json::from_str(text)?;
+        let n = file.joints.len();
+        let kp = file.kp.resolve(n, "kp")?;
+        let kd = file.kd.resolve(n, "kd")?;
+        Self::new(file.joints, kp, kd, file.interpolation, file.keyframes)
+    }
+
+    /// Read and parse a JSON sequence file.
+    pub fn read(path: impl AsRef<Path>) -> Result<Self, SequenceError> {
+        Self::from_json(&std::fs::read_to_string(path)?)
+    }
+
+    /// Import the `JointDisplacement` component of a Choreonoid `.seq` body
+    /// motion. Its parts map to consecutive motors starting at
+    /// `first_joint`; every frame becomes a keyframe lasting one frame
+    /// period, except the first, which is reached over `lead_in` seconds.
+    ///
+    /// Only the layout written by Choreonoid is understood: block-style
+    /// component lists with one flow-style `- [ ... ]` row per frame.
+    pub fn from_choreonoid_seq(
+        text: &str,
+        first_joint: usize,
+        lead_in: f32,
+        kp: f32,
+        kd: f32,
+    ) -> Result<Self, SequenceError> {
+        let mut lines = text
+            .lines()
+            .skip_while(|l| l.trim() != "content: JointDisplacement");
+        if lines.next().is_none() {
+            return Err(invalid("no JointDisplacement component"));
+        }
+
+        let mut frame_rate = None;
+        let mut num_parts = None;
+        for line in lines.by_ref() {
+            let line = line.trim();
+            if line == "frames:" {
+                break;
+            }
+            if let Some(v) = line.strip_prefix("frame_rate:") {
+                frame_rate = v.trim().parse::<f32>().ok();
+            } else if let Some(v) = line.strip_prefix("num_parts:") {
+                num_parts = v.trim().parse::<usize>().ok();
+            }
+        }
+        let frame_rate = frame_rate
+            .filter(|r| *r > 0.0)
+            .ok_or_else(|| invalid("missing frame_rate"))?;
+        let num_parts = num_parts.ok_or_else(|| invalid("missing num_parts"))?;
+
+        let period = 1.0 / frame_rate;
+        let mut keyframes = Vec::new();
+        for line in lines {
+            let Some(row) = line
+                .trim()
+                .strip_prefix("- [")
+                .and_then(|r| r.strip_suffix(']'))
+            else {
+                break;
+            };
+            let q = row
+                .split(',')
+                .map(|v| v.trim().parse::<f32>())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| invalid(format!("frame {}: {e}", keyframes.len())))?;
+            let duration = if keyframes.is_empty() { lead_in } else { period };
+            keyframes.push(Keyframe {
+                duration,
+                q,
+                kp: None,
+                kd: None,
+            });
+        }
+
+        let joints = (first_joint..first_joint + num_parts).collect();
+        Self::new(
+            joints,
+            vec![kp; num_parts],
+            vec![kd; num_parts],
+            Interpolation::Linear,
+            keyframes,
+        )
+    }
+
+    /// Read and import a Choreonoid `.seq` file. See
+    /// [`MotionSequence::from_choreonoid_seq`].
+    pub fn read_choreonoid_seq(
+        path: impl AsRef<Path>,
+        first_joint: usize,
+        lead_in: f32,
+        kp: f32,
+        kd: f32,
+    ) -> Result<Self, SequenceError> {
+        let text = std::fs::read_to_string(path)?;
+        Self::from_choreonoid_seq(&text, first_joint, lead_in, kp, kd)
+    }
+
+    /// HG motor indices driven by the sequence.
+    pub fn joints(&self) -> &[usize] {
+        &self.joints
+    }
+
+    /// Interpolation between keyframes.
+    pub fn interpolation(&self) -> Interpolation {
+        self.interpolation
+    }
+
+    /// Number of keyframes.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Always false; a sequence has at least one keyframe.
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Total duration in seconds.
+    pub fn duration(&self) -> f32 {
+        self.frames.last().map_or(0.0, |f| f.end)
+    }
+
+    /// Targets of the last keyframe.
+    pub fn final_pose(&self) -> &[f32] {
+        &self.frames[self.frames.len() - 1].q
+    }
+
+    /// Write the setpoint at time `t` into the sequence joints of `cmd`.
+    /// `start` is the pose, one value per sequence joint, that the first
+    /// keyframe is approached from. Times past the end hold the last
+    /// keyframe. Motors not in the sequence are left untouched.
+    pub fn apply(&self, t: f32, start: &[f32], cmd: &mut LowCmd) -> Result<(), SequenceError> {
+        if start.len() != self.joints.len() {
+            return Err(invalid(format!(
+                "start pose has {} values for {} joints",
+                start.len(),
+                self.joints.len()
+            )));
+        }
+        let index = self.frames.partition_point(|f| f.end <= t);
+        let (from, to, s) = match self.frames.get(index) {
+            Some(to) => {
+                let (from, begin) = match index.checked_sub(1) {
+                    Some(prev) => (Some(&self.frames[prev]), self.frames[prev].end),
+                    None => (None, 0.0),
+                };
+                let span = to.end - begin;
+                let s = if span > 0.0 { ((t - begin) / span).clamp(0.0, 1.0) } else { 1.0 };
+                (from, to, self.interpolation.shape(s))
+            }
+            None => (None, &self.frames[self.frames.len() - 1], 1.0),
+        };
+
+        let lerp = |a: f32, b: f32| a + (b - a) * s;
+        for (j, &motor) in self.joints.iter().enumerate() {
+            let (q0, kp0, kd0) = match from {
+                Some(f) => (f.q[j], f.kp[j], f.kd[j]),
+                None => (start[j], to.kp[j], to.kd[j]),
+            };
+            let m = &mut cmd.motor_cmd[motor];
+            m.mode = 1;
+            m.q = lerp(q0, to.q[j]);
+            m.dq = 0.0;
+            m.tau = 0.0;
+            m.kp = lerp(kp0, to.kp[j]);
+            m.kd = lerp(kd0, to.kd[j]);
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for MotionSequence {
+    type Err = SequenceError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_json(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// First frames of each component of `g1/low_level/behavior_lib/motion.seq`.
+    const MOTION_SEQ_EXCERPT: &str = r"type: CompositeSeq
+content: BodyMotion
+format_version: 4
+frame_rate: 500
+num_frames: 3817
+components:
+  -
+    type: MultiSE3Seq
+    content: LinkPosition
+    frame_rate: 500
+    num_frames: 3817
+    num_parts: 1
+    SE3Format: XYZQWQXQYQZ
+    frames:
+      - [ [ 0, 0, 0, 1, 0, 0, 0 ] ]
+  -
+    type: MultiValueSeq
+    content: JointDisplacement
+    frame_rate: 500
+    num_frames: 3817
+    num_parts: 14
+    frames:
+      - [ 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0 ]
+      - [ 7.87320505e-05, 5.98446915e-05, -0.000137732802, -0.000162916933, 0.000334613185, 0.000204073727, -9.2719622e-06, 7.90681158e-05, -6.02467397e-05, 0.000138020198, -0.000163261914, -0.000334461051, 0.00020465349, 9.18847926e-06 ]
+      - [ 0.000312907365, 0.000238007017, -0.000546440173, -0.000646037799, 0.00132764901, 0.000809023587, -3.6313292e-05, 0.000314239289, -0.00023959673, 0.000547579175, -0.000647398329, -0.00132704643, 0.000811318674, 3.59819297e-05 ]
+  -
+    type: Vector3Seq
+    content: ZMP
+    frame_rate: 500
+    num_frames: 3817
+    frames:
+";
+
+    const TWO_JOINTS: &str = r#"{
+        "joints": [15, 22],
+        "kp": 40.0,
+        "kd": [1.0, 2.0],
+        "keyframes": [
+            { "duration": 1.0, "q": [0.5, -0.5] },
+            { "duration": 2.0, "q": [1.0, 0.0], "kp": [60.0, 80.0] }
+        ]
+    }"#;
+
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() < 1e-6, "{a} != {b}");
+    }
+
+    #[test]
+    fn from_json_resolves_uniform_and_per_joint_gains() {
+        let seq = MotionSequence::from_json(TWO_JOINTS).unwrap();
+        assert_eq!(seq.joints(), &[15, 22]);
+        assert_eq!(seq.interpolation(), Interpolation::Linear);
+        assert_eq!(seq.len(), 2);
+        assert_close(seq.duration(), 3.0);
+        assert_eq!(seq.frames[0].kp, vec![40.0, 40.0]);
+        assert_eq!(seq.frames[0].kd, vec![1.0, 2.0]);
+        assert_eq!(seq.frames[1].kp, vec![60.0, 80.0]);
+        assert_eq!(seq.frames[1].kd, vec![1.0, 2.0]);
+    }
+
+    #[test]
+    fn from_json_rejects_wrong_lengths() {
+        let bad_gains = TWO_JOINTS.replace("[1.0, 2.0]", "[1.0, 2.0, 3.0]");
+        assert!(matches!(MotionSequence::from_json(&bad_gains), Err(SequenceError::Invalid(_))));
+
+        let bad_q = TWO_JOINTS.replace("[0.5, -0.5]", "[0.5]");
+        assert!(matches!(MotionSequence::from_json(&bad_q), Err(SequenceError::Invalid(_))));
+
+        let bad_override = TWO_JOINTS.replace("[60.0, 80.0]", "[60.0]");
+        assert!(matches!(MotionSequence::from_json(&bad_override), Err(SequenceError::Invalid(_))));
+    }
+
+    #[test]
+    fn from_json_rejects_non_finite_values() {
+        let keyframes = vec![Keyframe {
+            duration: 1.0,
+            q: vec![f32::NAN],
+            kp: None,
+            kd: None,
+        }];
+        let err = MotionSequence::new(vec![15], vec![40.0], vec![1.0], Interpolation::Linear, keyframes);
+        assert!(matches!(err, Err(SequenceError::Invalid(_))));
+
+        let bad_duration = TWO_JOINTS.replace("\"duration\": 2.0", "\"duration\": -2.0");
+        assert!(matches!(MotionSequence::from_json(&bad_duration), Err(SequenceError::Invalid(_))));
+
+        // JSON has no NaN literal; overflowing numbers are rejected by the parser.
+        let overflow = TWO_JOINTS.replace("[1.0, 0.0]", "[1e999, 0.0]");
+        assert!(MotionSequence::from_json(&overflow).is_err());
+    }
+
+    #[test]
+    fn from_choreonoid_seq_reads_joint_displacement() {
+        let seq = MotionSequence::from_choreonoid_seq(MOTION_SEQ_EXCERPT, 15, 3.0, 40.0, 1.0).unwrap();
+        assert_eq!(seq.joints(), (15..29).collect::<Vec<_>>().as_slice());
+        assert_eq!(seq.len(), 3);
+        assert_close(seq.duration(), 3.0 + 2.0 / 500.0);
+        assert_close(seq.frames[1].q[0], 7.873205e-5);
+        assert_close(seq.final_pose()[13], 3.598193e-5);
+        assert_eq!(seq.frames[2].kp, vec![40.0; 14]);
+    }
+
+    #[test]
+    fn from_choreonoid_seq_requires_joint_displacement() {
+        let text = MOTION_SEQ_EXCERPT.replace("JointDisplacement", "JointVelocity");
+        assert!(matches!(
+            MotionSequence::from_choreonoid_seq(&text, 15, 3.0, 40.0, 1.0),
+            Err(SequenceError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn apply_interpolates_between_keyframes() {
+        let seq = MotionSequence::from_json(TWO_JOINTS).unwrap();
+        let start = [0.0, 0.0];
+        let mut cmd = LowCmd::default();
+
+        seq.apply(0.0, &start, &mut cmd).unwrap();
+        assert_eq!(cmd.motor_cmd[15].mode, 1);
+        assert_close(cmd.motor_cmd[15].q, 0.0);
+        assert_close(cmd.motor_cmd[22].q, 0.0);
+
+        seq.apply(0.5, &start, &mut cmd).unwrap();
+        assert_close(cmd.motor_cmd[15].q, 0.25);
+        assert_close(cmd.motor_cmd[22].q, -0.25);
+
+        // Exactly on a keyframe: that keyframe's targets and gains.
+        seq.apply(1.0, &start, &mut cmd).unwrap();
+        assert_close(cmd.motor_cmd[15].q, 0.5);
+        assert_close(cmd.motor_cmd[22].q, -0.5);
+        assert_close(cmd.motor_cmd[22].kp, 40.0);
+
+        seq.apply(2.0, &start, &mut cmd).unwrap();
+        assert_close(cmd.motor_cmd[15].q, 0.75);
+        assert_close(cmd.motor_cmd[22].kp, 60.0);
+        assert_close(cmd.motor_cmd[22].kd, 2.0);
+
+        // Past the end: hold the last keyframe.
+        seq.apply(10.0, &start, &mut cmd).unwrap();
+        assert_close(cmd.motor_cmd[15].q, 1.0);
+        assert_close(cmd.motor_cmd[22].q, 0.0);
+        assert_close(cmd.motor_cmd[15].kp, 60.0);
+
+        // Motors outside the sequence are untouched.
+        assert_eq!(cmd.motor_cmd[16].mode, 0);
+    }
+
+    #[test]
+    fn apply_rejects_short_start_pose() {
+        let seq = MotionSequence::from_json(TWO_JOINTS).unwrap();
+        let mut cmd = LowCmd::default();
+        assert!(matches!(seq.apply(0.0, &[0.0], &mut cmd), Err(SequenceError::Invalid(_))));
+    }
+}
@@ -0,0 +1,2 @@
+//! Audio client for A2. The service is identical to the G1 one.
+pub use crate::robot::g1::audio::*;
@@ -0,0 +1,3 @@
+//! A2 robot clients.
+pub mod audio;
+pub mod sport;
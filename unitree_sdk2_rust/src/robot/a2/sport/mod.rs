@@ -0,0 +1,6 @@
+//! Sport mode client for A2.
+pub mod sport_api;
+pub mod sport_client;
+pub mod sport_error;
+
+pub use sport_client::{FsmState, SportClient, SportState};
@@ -0,0 +1,18 @@
+//! A2 sport API service name, version, and method ID constants.
+
+pub const ROBOT_SPORT_SERVICE_NAME: &str = "sport";
+pub const ROBOT_SPORT_API_VERSION: &str = "1.0.0.1";
+
+pub const ROBOT_SPORT_API_ID_DAMP: i32 = 1001;
+pub const ROBOT_SPORT_API_ID_BALANCESTAND: i32 = 1002;
+pub const ROBOT_SPORT_API_ID_STOPMOVE: i32 = 1003;
+pub const ROBOT_SPORT_API_ID_STANDUP: i32 = 1004;
+pub const ROBOT_SPORT_API_ID_STANDDOWN: i32 = 1005;
+pub const ROBOT_SPORT_API_ID_RECOVERYSTAND: i32 = 1006;
+pub const ROBOT_SPORT_API_ID_EULER: i32 = 1007;
+pub const ROBOT_SPORT_API_ID_MOVE: i32 = 1008;
+pub const ROBOT_SPORT_API_ID_SWITCHGAIT: i32 = 1011;
+pub const ROBOT_SPORT_API_ID_BODYHEIGHT: i32 = 1013;
+pub const ROBOT_SPORT_API_ID_SPEEDLEVEL: i32 = 1015;
+pub const ROBOT_SPORT_API_ID_GETSTATE: i32 = 1034;
+pub const ROBOT_SPORT_API_ID_SETAUTORECOVERY: i32 = 1040;
@@ -0,0 +1,292 @@
+//! SportClient - high-level sport-mode API for the A2 robot.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use serde_json::Value;
+
+use crate::rpc::{decode, JsonData, RpcClient};
+use super::sport_api::*;
+
+/// A2 sport FSM state, as reported by `GETSTATE` and in
+/// `SportModeState::mode`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(i32)]
+pub enum FsmState {
+    Passive = 0,
+    StandDown = 1,
+    StandUp = 2,
+    DefaultMode = 3,
+    RunningMode = 4,
+    ClimbMode = 5,
+    LeftSideGait = 6,
+    RightSideGait = 7,
+    HandStand = 8,
+    BipedStand = 9,
+    FrontFlip = 10,
+    BackFlip = 11,
+    Recovery = 12,
+    BaseHeightCtrl = 13,
+}
+
+impl FsmState {
+    /// Name used by the robot firmware, e.g. `"DEFAULT_MODE"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            FsmState::Passive => "PASSIVE",
+            FsmState::StandDown => "STAND_DOWN",
+            FsmState::StandUp => "STAND_UP",
+            FsmState::DefaultMode => "DEFAULT_MODE",
+            FsmState::RunningMode => "RUNNING_MODE",
+            FsmState::ClimbMode => "CLIMB_MODE",
+            FsmState::LeftSideGait => "LEFT_SIDE_GAIT",
+            FsmState::RightSideGait => "RIGHT_SIDE_GAIT",
+            FsmState::HandStand => "HANDSTAND",
+            FsmState::BipedStand => "BIPED_STAND",
+            FsmState::FrontFlip => "FRONT_FLIP",
+            FsmState::BackFlip => "BACK_FLIP",
+            FsmState::Recovery => "RECOVERY",
+            FsmState::BaseHeightCtrl => "BASE_HEIGHT_CTRL",
+        }
+    }
+}
+
+impl TryFrom<i32> for FsmState {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::Passive,
+            1 => Self::StandDown,
+            2 => Self::StandUp,
+            3 => Self::DefaultMode,
+            4 => Self::RunningMode,
+            5 => Self::ClimbMode,
+            6 => Self::LeftSideGait,
+            7 => Self::RightSideGait,
+            8 => Self::HandStand,
+            9 => Self::BipedStand,
+            10 => Self::FrontFlip,
+            11 => Self::BackFlip,
+            12 => Self::Recovery,
+            13 => Self::BaseHeightCtrl,
+            other => return Err(other),
+        })
+    }
+}
+
+/// Decoded `GETSTATE` response. A field is `None` when the robot left it
+/// out or sent a value that does not parse; the full reply is kept in
+/// `raw`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SportState {
+    pub fsm_id: Option<i32>,
+    pub fsm_name: Option<String>,
+    pub speed_level: Option<i32>,
+    pub auto_recovery_switch: Option<bool>,
+    pub process_state: Option<i32>,
+    pub raw: HashMap<String, Value>,
+}
+
+impl SportState {
+    /// The FSM state, if `fsm_id` is present and a known one.
+    pub fn fsm(&self) -> Option<FsmState> {
+        FsmState::try_from(self.fsm_id?).ok()
+    }
+
+    /// Build from the string-valued map returned by the robot.
+    fn from_map(raw: HashMap<String, Value>) -> Self {
+        Self {
+            fsm_id: field(&raw, "fsm_id"),
+            fsm_name: field(&raw, "fsm_name"),
+            speed_level: field(&raw, "speed_level"),
+            auto_recovery_switch: field::<i32>(&raw, "auto_recovery_switch").map(|v| v != 0),
+            process_state: field(&raw, "process_state"),
+            raw,
+        }
+    }
+}
+
+/// Parse one entry of the `GETSTATE` map. The robot sends every value as a
+/// string, but plain JSON numbers are accepted too.
+fn field<T: FromStr>(map: &HashMap<String, Value>, key: &str) -> Option<T> {
+    let value = map.get(key)?;
+    let parsed = match value {
+        Value::String(s) => s.trim().parse().ok(),
+        Value::Bool(b) => (*b as i32).to_string().parse().ok(),
+        Value::Number(_) => value.to_string().parse().ok(),
+        _ => None,
+    };
+    if parsed.is_none() {
+        log::warn!("A2 SportClient get_state: invalid value for '{}': {}", key, value);
+    }
+    parsed
+}
+
+/// Controls the A2 robot's sport/locomotion modes.
+pub struct SportClient {
+    rpc: RpcClient,
+}
+
+impl SportClient {
+    /// Create a new `SportClient`.
+    pub fn new() -> Self {
+        Self {
+            rpc: RpcClient::new(ROBOT_SPORT_SERVICE_NAME, false),
+        }
+    }
+
+    /// Set the RPC timeout in seconds.
+    pub fn set_timeout(&mut self, timeout: f32) {
+        self.rpc.set_timeout(timeout);
+    }
+
+    /// Initialize the client.
+    pub fn init(&mut self) {
+        self.rpc.set_api_version(ROBOT_SPORT_API_VERSION);
+        for api_id in [
+            ROBOT_SPORT_API_ID_DAMP,
+            ROBOT_SPORT_API_ID_BALANCESTAND,
+            ROBOT_SPORT_API_ID_STOPMOVE,
+            ROBOT_SPORT_API_ID_STANDUP,
+            ROBOT_SPORT_API_ID_STANDDOWN,
+            ROBOT_SPORT_API_ID_RECOVERYSTAND,
+            ROBOT_SPORT_API_ID_EULER,
+            ROBOT_SPORT_API_ID_MOVE,
+            ROBOT_SPORT_API_ID_SWITCHGAIT,
+            ROBOT_SPORT_API_ID_BODYHEIGHT,
+            ROBOT_SPORT_API_ID_SPEEDLEVEL,
+            ROBOT_SPORT_API_ID_SETAUTORECOVERY,
+            ROBOT_SPORT_API_ID_GETSTATE,
+        ] {
+            self.rpc.register_api(api_id, 0);
+        }
+        log::info!(
+            "A2 SportClient init: service={} version={}",
+            ROBOT_SPORT_SERVICE_NAME,
+            ROBOT_SPORT_API_VERSION
+        );
+    }
+
+    /// Damp all motors.
+    pub fn damp(&self) -> i32 {
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_DAMP, "{}")
+    }
+
+    /// Enter balance stand mode.
+    pub fn balance_stand(&self) -> i32 {
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_BALANCESTAND, "{}")
+    }
+
+    /// Stop all movement.
+    pub fn stop_move(&self) -> i32 {
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_STOPMOVE, "{}")
+    }
+
+    /// Stand up.
+    pub fn stand_up(&self) -> i32 {
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_STANDUP, "{}")
+    }
+
+    /// Stand down (lie down).
+    pub fn stand_down(&self) -> i32 {
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_STANDDOWN, "{}")
+    }
+
+    /// Recovery stand from fallen state.
+    pub fn recovery_stand(&self) -> i32 {
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_RECOVERYSTAND, "{}")
+    }
+
+    /// Set body orientation (Euler angles in radians).
+    pub fn euler(&self, roll: f32, pitch: f32, yaw: f32) -> i32 {
+        let p = format!(r#"{{"x":{roll},"y":{pitch},"z":{yaw}}}"#);
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_EULER, &p)
+    }
+
+    /// Move with velocity (m/s for vx/vy, rad/s for vyaw).
+    pub fn move_cmd(&self, vx: f32, vy: f32, vyaw: f32) -> i32 {
+        let p = format!(r#"{{"x":{vx},"y":{vy},"z":{vyaw}}}"#);
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_MOVE, &p)
+    }
+
+    /// Switch gait by FSM id (e.g. `FsmState::DefaultMode as i32`).
+    pub fn switch_gait(&self, gait_type: i32) -> i32 {
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_SWITCHGAIT, &JsonData::encode(gait_type))
+    }
+
+    /// Set body height, in meters.
+    pub fn body_height(&self, height: f32) -> i32 {
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_BODYHEIGHT, &JsonData::encode(height))
+    }
+
+    /// Set speed level.
+    pub fn speed_level(&self, level: i32) -> i32 {
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_SPEEDLEVEL, &JsonData::encode(level))
+    }
+
+    /// Enable or disable automatic recovery after a fall.
+    pub fn set_auto_recovery(&self, enable: bool) -> i32 {
+        let p = JsonData::encode(enable as i32);
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_SETAUTORECOVERY, &p)
+    }
+
+    /// Get the sport service state. Returns `Ok(SportState)` or
+    /// `Err(api_error_code)`; the error is `ROBOT_ERR_CLIENT_API_DATA` only
+    /// when the reply is not a JSON object.
+    pub fn get_state(&self) -> Result<SportState, i32> {
+        let data = self.rpc.call(ROBOT_SPORT_API_ID_GETSTATE, "{}")?;
+        Ok(SportState::from_map(decode(&data)?))
+    }
+}
+
+impl Default for SportClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn map(json: &str) -> HashMap<String, Value> {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn field_accepts_strings_and_numbers() {
+        let m = map(r#"{"a":"3","b":4,"c":" 5 ","d":true,"e":"DEFAULT_MODE","f":1.5}"#);
+        assert_eq!(field::<i32>(&m, "a"), Some(3));
+        assert_eq!(field::<i32>(&m, "b"), Some(4));
+        assert_eq!(field::<i32>(&m, "c"), Some(5));
+        assert_eq!(field::<i32>(&m, "d"), Some(1));
+        assert_eq!(field::<String>(&m, "e").as_deref(), Some("DEFAULT_MODE"));
+        assert_eq!(field::<f32>(&m, "f"), Some(1.5));
+    }
+
+    #[test]
+    fn field_rejects_missing_and_malformed_values() {
+        let m = map(r#"{"a":"three","b":1.5,"c":null,"d":[1]}"#);
+        assert_eq!(field::<i32>(&m, "missing"), None);
+        assert_eq!(field::<i32>(&m, "a"), None);
+        assert_eq!(field::<i32>(&m, "b"), None);
+        assert_eq!(field::<i32>(&m, "c"), None);
+        assert_eq!(field::<i32>(&m, "d"), None);
+    }
+
+    #[test]
+    fn state_keeps_the_fields_that_parse() {
+        let state = SportState::from_map(map(
+            r#"{"fsm_id":"3","fsm_name":"DEFAULT_MODE","speed_level":"x","auto_recovery_switch":"1","extra":"7"}"#,
+        ));
+        assert_eq!(state.fsm(), Some(FsmState::DefaultMode));
+        assert_eq!(state.fsm_name.as_deref(), Some("DEFAULT_MODE"));
+        assert_eq!(state.speed_level, None);
+        assert_eq!(state.auto_recovery_switch, Some(true));
+        assert_eq!(state.process_state, None);
+        assert_eq!(state.raw["extra"], "7");
+
+        assert_eq!(SportState::from_map(map(r#"{"fsm_id":42}"#)).fsm(), None);
+    }
+}
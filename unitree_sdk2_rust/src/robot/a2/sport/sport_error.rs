@@ -0,0 +1,4 @@
+//! A2 sport service error codes.
+
+pub const ROBOT_SPORT_ERR_SERVER_OVERTIME: i32 = 4201;
+pub const ROBOT_SPORT_ERR_SERVER_NOT_INIT: i32 = 4205;
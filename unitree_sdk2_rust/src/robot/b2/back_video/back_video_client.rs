@@ -0,0 +1,57 @@
+//! BackVideoClient - JPEG samples from the B2 back camera.
+
+use crate::camera::CameraSource;
+use crate::rpc::RpcClient;
+use super::back_video_api::*;
+
+/// Client for the B2 `back_videohub` service.
+pub struct BackVideoClient {
+    rpc: RpcClient,
+}
+
+impl BackVideoClient {
+    /// Create a new `BackVideoClient`.
+    pub fn new() -> Self {
+        Self {
+            rpc: RpcClient::new(ROBOT_BACK_VIDEO_SERVICE_NAME, false),
+        }
+    }
+
+    /// Set the RPC timeout in seconds.
+    pub fn set_timeout(&mut self, timeout: f32) {
+        self.rpc.set_timeout(timeout);
+    }
+
+    /// Initialize the client.
+    pub fn init(&mut self) {
+        self.rpc.set_api_version(ROBOT_BACK_VIDEO_API_VERSION);
+        self.rpc.register_api(ROBOT_BACK_VIDEO_API_ID_GETIMAGESAMPLE, 0);
+        log::info!(
+            "BackVideoClient init: service={} version={}",
+            ROBOT_BACK_VIDEO_SERVICE_NAME,
+            ROBOT_BACK_VIDEO_API_VERSION
+        );
+    }
+
+    /// Fetch the latest JPEG frame. Returns the encoded image or
+    /// `Err(api_error_code)`.
+    pub fn get_image_sample(&self) -> Result<Vec<u8>, i32> {
+        self.rpc.call_for_binary(ROBOT_BACK_VIDEO_API_ID_GETIMAGESAMPLE, "{}")
+    }
+}
+
+impl Default for BackVideoClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CameraSource for BackVideoClient {
+    fn source_name(&self) -> &str {
+        self.rpc.service_name()
+    }
+
+    fn image_sample(&self) -> Result<Vec<u8>, i32> {
+        self.get_image_sample()
+    }
+}
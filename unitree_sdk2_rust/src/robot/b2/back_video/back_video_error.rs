@@ -0,0 +1,3 @@
+//! Back camera service error codes.
+
+pub const ROBOT_BACK_VIDEO_ERR_COMM: i32 = 100;
@@ -0,0 +1,6 @@
+//! Back camera client for B2.
+pub mod back_video_api;
+pub mod back_video_client;
+pub mod back_video_error;
+
+pub use back_video_client::BackVideoClient;
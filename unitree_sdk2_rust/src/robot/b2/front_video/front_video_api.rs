@@ -0,0 +1,6 @@
+//! Front camera service name, version, and method ID constants.
+
+pub const ROBOT_FRONT_VIDEO_SERVICE_NAME: &str = "front_videohub";
+pub const ROBOT_FRONT_VIDEO_API_VERSION: &str = "1.0.0.0";
+
+pub const ROBOT_FRONT_VIDEO_API_ID_GETIMAGESAMPLE: i32 = 1001;
@@ -0,0 +1,3 @@
+//! Front camera service error codes.
+
+pub const ROBOT_FRONT_VIDEO_ERR_COMM: i32 = 100;
@@ -0,0 +1,6 @@
+//! Front camera client for B2.
+pub mod front_video_api;
+pub mod front_video_client;
+pub mod front_video_error;
+
+pub use front_video_client::FrontVideoClient;
@@ -0,0 +1,5 @@
+//! B2 robot clients.
+pub mod back_video;
+pub mod front_video;
+pub mod motion_switcher;
+pub mod sport;
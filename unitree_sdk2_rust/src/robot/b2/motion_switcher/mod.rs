@@ -0,0 +1,6 @@
+//! Motion switcher client, shared by Go2, B2 and the humanoids.
+pub mod motion_switcher_api;
+pub mod motion_switcher_client;
+pub mod motion_switcher_error;
+
+pub use motion_switcher_client::{ModeName, MotionSwitcherClient, ReleasedMode};
@@ -0,0 +1,10 @@
+//! Motion switcher service name, version, and method ID constants.
+
+pub const MOTION_SWITCHER_SERVICE_NAME: &str = "motion_switcher";
+pub const MOTION_SWITCHER_API_VERSION: &str = "1.0.0.1";
+
+pub const MOTION_SWITCHER_API_ID_CHECK_MODE: i32 = 1001;
+pub const MOTION_SWITCHER_API_ID_SELECT_MODE: i32 = 1002;
+pub const MOTION_SWITCHER_API_ID_RELEASE_MODE: i32 = 1003;
+pub const MOTION_SWITCHER_API_ID_SET_SILENT: i32 = 1004;
+pub const MOTION_SWITCHER_API_ID_GET_SILENT: i32 = 1005;
@@ -0,0 +1,161 @@
+//! MotionSwitcherClient - selects which motion controller drives the robot.
+//!
+//! Low-level control (`rt/lowcmd`) fights the built-in sport controller unless
+//! that controller is released first. [`ReleasedMode`] does this and restores
+//! the previous controller when dropped.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::rpc::{decode, RpcClient};
+use super::motion_switcher_api::*;
+use super::motion_switcher_error::SWITCH_ERR_EXEC_RELEASE;
+
+/// Active motion mode reported by `check_mode`. An empty `name` means no
+/// controller is active.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ModeName {
+    #[serde(default)]
+    pub form: String,
+    #[serde(default)]
+    pub name: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct SilentData {
+    silent: bool,
+}
+
+/// Client for the `motion_switcher` service.
+pub struct MotionSwitcherClient {
+    rpc: RpcClient,
+}
+
+impl MotionSwitcherClient {
+    /// Create a new `MotionSwitcherClient`.
+    pub fn new() -> Self {
+        Self {
+            rpc: RpcClient::new(MOTION_SWITCHER_SERVICE_NAME, false),
+        }
+    }
+
+    /// Set the RPC timeout in seconds.
+    pub fn set_timeout(&mut self, timeout: f32) {
+        self.rpc.set_timeout(timeout);
+    }
+
+    /// Initialize the client.
+    pub fn init(&mut self) {
+        self.rpc.set_api_version(MOTION_SWITCHER_API_VERSION);
+        self.rpc.register_api(MOTION_SWITCHER_API_ID_CHECK_MODE, 0);
+        self.rpc.register_api(MOTION_SWITCHER_API_ID_SELECT_MODE, 0);
+        self.rpc.register_api(MOTION_SWITCHER_API_ID_RELEASE_MODE, 0);
+        self.rpc.register_api(MOTION_SWITCHER_API_ID_SET_SILENT, 0);
+        self.rpc.register_api(MOTION_SWITCHER_API_ID_GET_SILENT, 0);
+        log::info!(
+            "MotionSwitcherClient init: service={} version={}",
+            MOTION_SWITCHER_SERVICE_NAME,
+            MOTION_SWITCHER_API_VERSION
+        );
+    }
+
+    /// Query the active motion mode. Returns `Ok(ModeName)` or `Err(api_error_code)`.
+    pub fn check_mode(&self) -> Result<ModeName, i32> {
+        let data = self.rpc.call(MOTION_SWITCHER_API_ID_CHECK_MODE, "{}")?;
+        decode(&data)
+    }
+
+    /// Select a motion mode by name or alias (e.g. `"normal"`, `"ai"`).
+    pub fn select_mode(&self, name_or_alias: &str) -> i32 {
+        let p = serde_json::json!({ "name": name_or_alias }).to_string();
+        self.rpc.call_no_data(MOTION_SWITCHER_API_ID_SELECT_MODE, &p)
+    }
+
+    /// Release the active motion mode.
+    pub fn release_mode(&self) -> i32 {
+        self.rpc.call_no_data(MOTION_SWITCHER_API_ID_RELEASE_MODE, "{}")
+    }
+
+    /// Set silent flag.
+    pub fn set_silent(&self, silent: bool) -> i32 {
+        let p = format!(r#"{{"silent":{silent}}}"#);
+        self.rpc.call_no_data(MOTION_SWITCHER_API_ID_SET_SILENT, &p)
+    }
+
+    /// Get silent flag. Returns `Ok(bool)` or `Err(api_error_code)`.
+    pub fn get_silent(&self) -> Result<bool, i32> {
+        let data = self.rpc.call(MOTION_SWITCHER_API_ID_GET_SILENT, "{}")?;
+        Ok(decode::<SilentData>(&data)?.silent)
+    }
+}
+
+impl Default for MotionSwitcherClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Number of `check_mode` polls before giving up on a release.
+const RELEASE_ATTEMPTS: usize = 5;
+/// Delay between release attempts.
+const RELEASE_RETRY_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Keeps the built-in motion controller released for as long as it lives.
+///
+/// On drop, the mode that was active before [`ReleasedMode::acquire`] is
+/// selected again.
+pub struct ReleasedMode {
+    client: MotionSwitcherClient,
+    previous: ModeName,
+}
+
+impl ReleasedMode {
+    /// Release the active controller and verify via `check_mode` that none
+    /// remains. Returns `Err(api_error_code)` if the release cannot be verified.
+    pub fn acquire(client: MotionSwitcherClient) -> Result<Self, i32> {
+        let previous = client.check_mode()?;
+        if previous.name.is_empty() {
+            log::info!("No motion mode active, nothing to release");
+            return Ok(Self { client, previous });
+        }
+
+        for attempt in 1..=RELEASE_ATTEMPTS {
+            log::info!(
+                "Releasing motion mode '{}' (attempt {}/{})",
+                previous.name,
+                attempt,
+                RELEASE_ATTEMPTS
+            );
+            let ret = client.release_mode();
+            if ret != 0 {
+                log::warn!("release_mode failed: {}", ret);
+            }
+            std::thread::sleep(RELEASE_RETRY_INTERVAL);
+            if client.check_mode()?.name.is_empty() {
+                return Ok(Self { client, previous });
+            }
+        }
+
+        Err(SWITCH_ERR_EXEC_RELEASE)
+    }
+
+    /// The mode that was active before release and will be restored on drop.
+    pub fn previous(&self) -> &ModeName {
+        &self.previous
+    }
+}
+
+impl Drop for ReleasedMode {
+    fn drop(&mut self) {
+        if self.previous.name.is_empty() {
+            return;
+        }
+        log::info!("Restoring motion mode '{}'", self.previous.name);
+        let ret = self.client.select_mode(&self.previous.name);
+        if ret != 0 {
+            log::error!("Failed to restore motion mode '{}': {}", self.previous.name, ret);
+        }
+    }
+}
@@ -0,0 +1,11 @@
+//! Motion switcher service error codes.
+
+pub const SWITCH_ERR_PARAMETR: i32 = 7001;
+pub const SWITCH_ERR_BUSY: i32 = 7002;
+pub const SWITCH_ERR_EVENT: i32 = 7003;
+pub const SWITCH_ERR_NAME: i32 = 7004;
+pub const SWITCH_ERR_CMD: i32 = 7005;
+pub const SWITCH_ERR_EXEC_CHECK: i32 = 7006;
+pub const SWITCH_ERR_EXEC_SELECT: i32 = 7007;
+pub const SWITCH_ERR_EXEC_RELEASE: i32 = 7008;
+pub const SWITCH_ERR_CUSTOMIZE: i32 = 7009;
@@ -0,0 +1,8 @@
+//! Sport mode client for B2.
+pub mod sport_api;
+pub mod sport_client;
+
+/// B2 shares the Go2 sport service error codes.
+pub use crate::robot::go2::sport::sport_error;
+
+pub use sport_client::SportClient;
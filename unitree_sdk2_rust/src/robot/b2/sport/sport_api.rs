@@ -0,0 +1,26 @@
+//! B2 sport API service name, version, and method ID constants.
+
+pub const ROBOT_SPORT_SERVICE_NAME: &str = "sport";
+pub const ROBOT_SPORT_API_VERSION: &str = "1.0.0.1";
+
+pub const ROBOT_SPORT_API_ID_DAMP: i32 = 1001;
+pub const ROBOT_SPORT_API_ID_BALANCESTAND: i32 = 1002;
+pub const ROBOT_SPORT_API_ID_STOPMOVE: i32 = 1003;
+pub const ROBOT_SPORT_API_ID_STANDUP: i32 = 1004;
+pub const ROBOT_SPORT_API_ID_STANDDOWN: i32 = 1005;
+pub const ROBOT_SPORT_API_ID_RECOVERYSTAND: i32 = 1006;
+pub const ROBOT_SPORT_API_ID_MOVE: i32 = 1008;
+pub const ROBOT_SPORT_API_ID_SWITCHGAIT: i32 = 1011;
+pub const ROBOT_SPORT_API_ID_BODYHEIGHT: i32 = 1013;
+pub const ROBOT_SPORT_API_ID_SPEEDLEVEL: i32 = 1015;
+pub const ROBOT_SPORT_API_ID_TRAJECTORYFOLLOW: i32 = 1018;
+pub const ROBOT_SPORT_API_ID_CONTINUOUSGAIT: i32 = 1019;
+pub const ROBOT_SPORT_API_ID_MOVETOPOS: i32 = 1036;
+pub const ROBOT_SPORT_API_ID_SWITCHMOVEMODE: i32 = 1038;
+pub const ROBOT_SPORT_API_ID_HANDSTAND: i32 = 1039;
+pub const ROBOT_SPORT_API_ID_AUTORECOVERY_SET: i32 = 1040;
+pub const ROBOT_SPORT_API_ID_FREEWALK: i32 = 1045;
+pub const ROBOT_SPORT_API_ID_CLASSICWALK: i32 = 1049;
+pub const ROBOT_SPORT_API_ID_FASTWALK: i32 = 1050;
+pub const ROBOT_SPORT_API_ID_FREEEULER: i32 = 1051;
+pub const ROBOT_SPORT_API_ID_VISIONWALK: i32 = 1101;
@@ -0,0 +1,183 @@
+//! SportClient - high-level sport-mode API for the B2 robot.
+//!
+//! The B2 sport service shares its request format, path points and error
+//! codes with Go2 but uses a different set of API ids.
+
+use crate::idl::go2::PathPoint;
+use crate::robot::go2::sport::encode_path;
+use crate::rpc::{JsonData, RpcClient};
+use super::sport_api::*;
+
+/// Controls the B2 robot's sport/locomotion modes.
+pub struct SportClient {
+    rpc: RpcClient,
+}
+
+impl SportClient {
+    /// Create a new `SportClient`.
+    pub fn new(enable_lease: bool) -> Self {
+        Self {
+            rpc: RpcClient::new(ROBOT_SPORT_SERVICE_NAME, enable_lease),
+        }
+    }
+
+    /// Set the RPC timeout in seconds.
+    pub fn set_timeout(&mut self, timeout: f32) {
+        self.rpc.set_timeout(timeout);
+    }
+
+    /// Initialize the client.
+    pub fn init(&mut self) {
+        self.rpc.set_api_version(ROBOT_SPORT_API_VERSION);
+        for api_id in [
+            ROBOT_SPORT_API_ID_DAMP,
+            ROBOT_SPORT_API_ID_BALANCESTAND,
+            ROBOT_SPORT_API_ID_STOPMOVE,
+            ROBOT_SPORT_API_ID_STANDUP,
+            ROBOT_SPORT_API_ID_STANDDOWN,
+            ROBOT_SPORT_API_ID_RECOVERYSTAND,
+            ROBOT_SPORT_API_ID_MOVE,
+            ROBOT_SPORT_API_ID_SWITCHGAIT,
+            ROBOT_SPORT_API_ID_BODYHEIGHT,
+            ROBOT_SPORT_API_ID_SPEEDLEVEL,
+            ROBOT_SPORT_API_ID_TRAJECTORYFOLLOW,
+            ROBOT_SPORT_API_ID_CONTINUOUSGAIT,
+            ROBOT_SPORT_API_ID_MOVETOPOS,
+            ROBOT_SPORT_API_ID_SWITCHMOVEMODE,
+            ROBOT_SPORT_API_ID_HANDSTAND,
+            ROBOT_SPORT_API_ID_AUTORECOVERY_SET,
+            ROBOT_SPORT_API_ID_FREEWALK,
+            ROBOT_SPORT_API_ID_CLASSICWALK,
+            ROBOT_SPORT_API_ID_FASTWALK,
+            ROBOT_SPORT_API_ID_FREEEULER,
+            ROBOT_SPORT_API_ID_VISIONWALK,
+        ] {
+            self.rpc.register_api(api_id, 0);
+        }
+        log::info!(
+            "B2 SportClient init: service={} version={} enable_lease={}",
+            ROBOT_SPORT_SERVICE_NAME,
+            ROBOT_SPORT_API_VERSION,
+            self.rpc.enable_lease()
+        );
+    }
+
+    fn set_flag(&self, api_id: i32, flag: bool) -> i32 {
+        self.rpc.call_no_data(api_id, &JsonData::encode(flag))
+    }
+
+    /// Damp all motors.
+    pub fn damp(&self) -> i32 {
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_DAMP, "{}")
+    }
+
+    /// Enter balance stand mode.
+    pub fn balance_stand(&self) -> i32 {
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_BALANCESTAND, "{}")
+    }
+
+    /// Stop all movement.
+    pub fn stop_move(&self) -> i32 {
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_STOPMOVE, "{}")
+    }
+
+    /// Stand up.
+    pub fn stand_up(&self) -> i32 {
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_STANDUP, "{}")
+    }
+
+    /// Stand down (lie down).
+    pub fn stand_down(&self) -> i32 {
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_STANDDOWN, "{}")
+    }
+
+    /// Recovery stand from fallen state.
+    pub fn recovery_stand(&self) -> i32 {
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_RECOVERYSTAND, "{}")
+    }
+
+    /// Move with velocity (m/s for vx/vy, rad/s for vyaw).
+    pub fn move_cmd(&self, vx: f32, vy: f32, vyaw: f32) -> i32 {
+        let p = format!(r#"{{"x":{vx},"y":{vy},"z":{vyaw}}}"#);
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_MOVE, &p)
+    }
+
+    /// Switch gait (0: idle, 1: trot, 2: trot running, 3: forward climb
+    /// stair, 4: reverse climb stair).
+    pub fn switch_gait(&self, gait: i32) -> i32 {
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_SWITCHGAIT, &JsonData::encode(gait))
+    }
+
+    /// Set body height relative to the default stand height, in meters.
+    pub fn body_height(&self, height: f32) -> i32 {
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_BODYHEIGHT, &JsonData::encode(height))
+    }
+
+    /// Set speed level.
+    pub fn speed_level(&self, level: i32) -> i32 {
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_SPEEDLEVEL, &JsonData::encode(level))
+    }
+
+    /// Follow a time-stamped path.
+    ///
+    /// The path is validated client-side first; an invalid path returns
+    /// `ROBOT_SPORT_ERR_CLIENT_POINT_PATH` (4101) without contacting the robot.
+    pub fn trajectory_follow(&self, path: &[PathPoint]) -> i32 {
+        match encode_path(path) {
+            Ok(p) => self.rpc.call_no_data(ROBOT_SPORT_API_ID_TRAJECTORYFOLLOW, &p),
+            Err(code) => code,
+        }
+    }
+
+    /// Keep stepping in place while standing.
+    pub fn continuous_gait(&self, flag: bool) -> i32 {
+        self.set_flag(ROBOT_SPORT_API_ID_CONTINUOUSGAIT, flag)
+    }
+
+    /// Walk to a pose relative to the current one (meters, radians).
+    pub fn move_to_pos(&self, x: f32, y: f32, yaw: f32) -> i32 {
+        let p = format!(r#"{{"x":{x},"y":{y},"z":{yaw}}}"#);
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_MOVETOPOS, &p)
+    }
+
+    /// Switch between joystick (`false`) and API (`true`) move mode.
+    pub fn switch_move_mode(&self, flag: bool) -> i32 {
+        self.set_flag(ROBOT_SPORT_API_ID_SWITCHMOVEMODE, flag)
+    }
+
+    /// Vision-assisted walking.
+    pub fn vision_walk(&self, flag: bool) -> i32 {
+        self.set_flag(ROBOT_SPORT_API_ID_VISIONWALK, flag)
+    }
+
+    /// Hand stand.
+    pub fn hand_stand(&self, flag: bool) -> i32 {
+        self.set_flag(ROBOT_SPORT_API_ID_HANDSTAND, flag)
+    }
+
+    /// Set auto-recovery flag.
+    pub fn auto_recover_set(&self, flag: bool) -> i32 {
+        self.set_flag(ROBOT_SPORT_API_ID_AUTORECOVERY_SET, flag)
+    }
+
+    /// Free walk mode.
+    pub fn free_walk(&self) -> i32 {
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_FREEWALK, "{}")
+    }
+
+    /// Classic walk mode.
+    pub fn classic_walk(&self, flag: bool) -> i32 {
+        self.set_flag(ROBOT_SPORT_API_ID_CLASSICWALK, flag)
+    }
+
+    /// Fast walk mode.
+    pub fn fast_walk(&self, flag: bool) -> i32 {
+        self.set_flag(ROBOT_SPORT_API_ID_FASTWALK, flag)
+    }
+
+    /// Set body orientation while walking (Euler angles in radians).
+    pub fn euler(&self, roll: f32, pitch: f32, yaw: f32) -> i32 {
+        let p = format!(r#"{{"x":{roll},"y":{pitch},"z":{yaw}}}"#);
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_FREEEULER, &p)
+    }
+}
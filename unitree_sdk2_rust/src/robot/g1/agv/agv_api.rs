@@ -0,0 +1,14 @@
+//! G1 AGV service name, version, method ID and limit constants.
+
+pub const AGV_SERVICE_NAME: &str = "agv";
+pub const AGV_API_VERSION: &str = "1.0.0.1";
+
+pub const ROBOT_API_ID_AGV_MOVE: i32 = 1001;
+pub const ROBOT_API_ID_AGV_HEIGHT_ADJUST: i32 = 1002;
+
+/// Maximum linear velocity of the base (m/s).
+pub const AGV_MAX_VX: f32 = 1.5;
+/// Maximum angular velocity of the base (rad/s).
+pub const AGV_MAX_VYAW: f32 = 0.6;
+/// Column speed at full height command (m/s).
+pub const AGV_MAX_COLUMN_SPEED: f32 = 0.0765;
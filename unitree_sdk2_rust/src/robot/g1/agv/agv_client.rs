@@ -0,0 +1,127 @@
+//! AgvClient - velocity and column height control for the G1 wheeled base.
+
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::rpc::{JsonData, RpcClient};
+use super::agv_api::*;
+
+#[derive(Debug, Serialize)]
+struct MoveParameter {
+    vx: f32,
+    vy: f32,
+    vyaw: f32,
+}
+
+/// Client for the G1 `agv` service.
+pub struct AgvClient {
+    rpc: RpcClient,
+}
+
+impl AgvClient {
+    /// Create a new `AgvClient`.
+    pub fn new() -> Self {
+        Self {
+            rpc: RpcClient::new(AGV_SERVICE_NAME, false),
+        }
+    }
+
+    /// Set the RPC timeout in seconds.
+    pub fn set_timeout(&mut self, timeout: f32) {
+        self.rpc.set_timeout(timeout);
+    }
+
+    /// Initialize the client.
+    pub fn init(&mut self) {
+        self.rpc.set_api_version(AGV_API_VERSION);
+        self.rpc.register_api(ROBOT_API_ID_AGV_MOVE, 0);
+        self.rpc.register_api(ROBOT_API_ID_AGV_HEIGHT_ADJUST, 0);
+        log::info!(
+            "AgvClient init: service={} version={}",
+            AGV_SERVICE_NAME,
+            AGV_API_VERSION
+        );
+    }
+
+    /// Move with velocity. `vx` is in m/s (±`AGV_MAX_VX`), `vyaw` in rad/s
+    /// (±`AGV_MAX_VYAW`, positive is counter-clockwise). The base cannot move
+    /// sideways, so `vy` is ignored by the robot.
+    pub fn move_cmd(&self, vx: f32, vy: f32, vyaw: f32) -> i32 {
+        let p = serde_json::to_string(&MoveParameter { vx, vy, vyaw }).unwrap_or_default();
+        self.rpc.call_no_data(ROBOT_API_ID_AGV_MOVE, &p)
+    }
+
+    /// Drive the height column. `vz` is normalized to [-1.0, 1.0] and maps
+    /// linearly to ±`AGV_MAX_COLUMN_SPEED`.
+    pub fn height_adjust(&self, vz: f32) -> i32 {
+        self.rpc.call_no_data(ROBOT_API_ID_AGV_HEIGHT_ADJUST, &JsonData::encode(vz))
+    }
+}
+
+impl Default for AgvClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Re-sends a velocity command at a fixed interval while it is held.
+///
+/// The base stops when commands stop arriving, so continuous motion needs a
+/// steady stream of `move_cmd` calls. Update the target with
+/// [`VelocityHold::set`]; dropping the hold stops the thread and sends a
+/// zero velocity.
+pub struct VelocityHold {
+    client: Arc<AgvClient>,
+    target: Arc<Mutex<[f32; 3]>>,
+    running: Arc<Mutex<bool>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl VelocityHold {
+    /// Start re-sending a zero velocity every `interval`.
+    pub fn start(client: Arc<AgvClient>, interval: Duration) -> Self {
+        let target = Arc::new(Mutex::new([0.0f32; 3]));
+        let running = Arc::new(Mutex::new(true));
+
+        let handle = {
+            let client = Arc::clone(&client);
+            let target = Arc::clone(&target);
+            let running = Arc::clone(&running);
+            std::thread::spawn(move || {
+                while *running.lock().unwrap() {
+                    let [vx, vy, vyaw] = *target.lock().unwrap();
+                    let ret = client.move_cmd(vx, vy, vyaw);
+                    if ret != 0 {
+                        log::warn!("VelocityHold move_cmd failed: {}", ret);
+                    }
+                    std::thread::sleep(interval);
+                }
+            })
+        };
+
+        Self {
+            client,
+            target,
+            running,
+            handle: Some(handle),
+        }
+    }
+
+    /// Change the held velocity.
+    pub fn set(&self, vx: f32, vy: f32, vyaw: f32) {
+        *self.target.lock().unwrap() = [vx, vy, vyaw];
+    }
+}
+
+impl Drop for VelocityHold {
+    fn drop(&mut self) {
+        *self.running.lock().unwrap() = false;
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+        self.client.move_cmd(0.0, 0.0, 0.0);
+    }
+}
@@ -0,0 +1,5 @@
+//! G1 AGV service error codes.
+
+pub const ROBOT_G1_AGV_ERR_NOT_INIT: i32 = 9101;
+pub const ROBOT_G1_AGV_ERR_EXEC_MOVE: i32 = 9102;
+pub const ROBOT_G1_AGV_ERR_EXEC_HEIGHT_ADJUST: i32 = 9103;
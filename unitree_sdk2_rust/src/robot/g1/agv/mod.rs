@@ -0,0 +1,6 @@
+//! Wheeled base (AGV) client for G1.
+pub mod agv_api;
+pub mod agv_client;
+pub mod agv_error;
+
+pub use agv_client::{AgvClient, VelocityHold};
@@ -0,0 +1,9 @@
+//! G1 arm action service name, version, and method ID constants.
+
+pub const ARM_ACTION_SERVICE_NAME: &str = "arm";
+pub const ARM_ACTION_API_VERSION: &str = "1.0.0.14";
+
+pub const ROBOT_API_ID_ARM_ACTION_EXECUTE_ACTION: i32 = 7106;
+pub const ROBOT_API_ID_ARM_ACTION_GET_ACTION_LIST: i32 = 7107;
+pub const ROBOT_API_ID_ARM_ACTION_EXECUTE_CUSTOM_ACTION: i32 = 7108;
+pub const ROBOT_API_ID_ARM_ACTION_STOP_CUSTOM_ACTION: i32 = 7113;
@@ -0,0 +1,178 @@
+//! ArmActionClient - preset and taught upper-body gestures for the G1.
+//!
+//! The arm action server drives the arms through `rt/arm_sdk`. Some preset
+//! actions hold their last keyframe when done; send [`ArmAction::ReleaseArm`]
+//! or the same action again to release them.
+
+use serde_json::Value;
+
+use crate::rpc::{decode, RpcClient};
+use super::arm_action_api::*;
+
+/// Built-in arm gestures, by action id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(i32)]
+pub enum ArmAction {
+    ReleaseArm = 99,
+    TwoHandKiss = 11,
+    LeftKiss = 12,
+    RightKiss = 13,
+    HandsUp = 15,
+    Clap = 17,
+    HighFive = 18,
+    Hug = 19,
+    Heart = 20,
+    RightHeart = 21,
+    Reject = 22,
+    RightHandUp = 23,
+    XRay = 24,
+    FaceWave = 25,
+    HighWave = 26,
+    ShakeHand = 27,
+}
+
+impl ArmAction {
+    /// All built-in gestures.
+    pub const ALL: [ArmAction; 16] = [
+        ArmAction::ReleaseArm,
+        ArmAction::TwoHandKiss,
+        ArmAction::LeftKiss,
+        ArmAction::RightKiss,
+        ArmAction::HandsUp,
+        ArmAction::Clap,
+        ArmAction::HighFive,
+        ArmAction::Hug,
+        ArmAction::Heart,
+        ArmAction::RightHeart,
+        ArmAction::Reject,
+        ArmAction::RightHandUp,
+        ArmAction::XRay,
+        ArmAction::FaceWave,
+        ArmAction::HighWave,
+        ArmAction::ShakeHand,
+    ];
+
+    /// Action id sent to the robot.
+    pub fn id(self) -> i32 {
+        self as i32
+    }
+
+    /// Human-readable name, as shown in the Unitree app.
+    pub fn name(self) -> &'static str {
+        match self {
+            ArmAction::ReleaseArm => "release arm",
+            ArmAction::TwoHandKiss => "two-hand kiss",
+            ArmAction::LeftKiss => "left kiss",
+            ArmAction::RightKiss => "right kiss",
+            ArmAction::HandsUp => "hands up",
+            ArmAction::Clap => "clap",
+            ArmAction::HighFive => "high five",
+            ArmAction::Hug => "hug",
+            ArmAction::Heart => "heart",
+            ArmAction::RightHeart => "right heart",
+            ArmAction::Reject => "reject",
+            ArmAction::RightHandUp => "right hand up",
+            ArmAction::XRay => "x-ray",
+            ArmAction::FaceWave => "face wave",
+            ArmAction::HighWave => "high wave",
+            ArmAction::ShakeHand => "shake hand",
+        }
+    }
+}
+
+impl TryFrom<i32> for ArmAction {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        ArmAction::ALL
+            .into_iter()
+            .find(|a| a.id() == value)
+            .ok_or(value)
+    }
+}
+
+/// Client for the G1 `arm` service.
+pub struct ArmActionClient {
+    rpc: RpcClient,
+}
+
+impl ArmActionClient {
+    /// Create a new `ArmActionClient`.
+    pub fn new() -> Self {
+        Self {
+            rpc: RpcClient::new(ARM_ACTION_SERVICE_NAME, false),
+        }
+    }
+
+    /// Set the RPC timeout in seconds. Taught actions may take longer than
+    /// the presets, which all finish within 10 s.
+    pub fn set_timeout(&mut self, timeout: f32) {
+        self.rpc.set_timeout(timeout);
+    }
+
+    /// Initialize the client.
+    pub fn init(&mut self) {
+        self.rpc.set_api_version(ARM_ACTION_API_VERSION);
+        self.rpc.register_api(ROBOT_API_ID_ARM_ACTION_EXECUTE_ACTION, 0);
+        self.rpc.register_api(ROBOT_API_ID_ARM_ACTION_GET_ACTION_LIST, 0);
+        self.rpc.register_api(ROBOT_API_ID_ARM_ACTION_EXECUTE_CUSTOM_ACTION, 0);
+        self.rpc.register_api(ROBOT_API_ID_ARM_ACTION_STOP_CUSTOM_ACTION, 0);
+        log::info!(
+            "ArmActionClient init: service={} version={}",
+            ARM_ACTION_SERVICE_NAME,
+            ARM_ACTION_API_VERSION
+        );
+    }
+
+    /// Execute a preset action by raw id. Some ids are not shown in the app
+    /// and may make the robot fall; prefer [`ArmActionClient::execute`].
+    pub fn execute_action(&self, action_id: i32) -> i32 {
+        let p = format!(r#"{{"action_id":{action_id}}}"#);
+        self.rpc.call_no_data(ROBOT_API_ID_ARM_ACTION_EXECUTE_ACTION, &p)
+    }
+
+    /// Execute a built-in gesture.
+    pub fn execute(&self, action: ArmAction) -> i32 {
+        self.execute_action(action.id())
+    }
+
+    /// Execute a taught action by name.
+    pub fn execute_custom_action(&self, action_name: &str) -> i32 {
+        let p = serde_json::json!({ "action_name": action_name }).to_string();
+        self.rpc.call_no_data(ROBOT_API_ID_ARM_ACTION_EXECUTE_CUSTOM_ACTION, &p)
+    }
+
+    /// Stop the running taught action.
+    pub fn stop_custom_action(&self) -> i32 {
+        self.rpc.call_no_data(ROBOT_API_ID_ARM_ACTION_STOP_CUSTOM_ACTION, "{}")
+    }
+
+    /// Get the actions available on the robot, as the raw JSON reply; its
+    /// layout is not documented. Returns `Err(api_error_code)` on failure,
+    /// or 3106 if the reply is not JSON.
+    pub fn get_action_list(&self) -> Result<Value, i32> {
+        let data = self.rpc.call(ROBOT_API_ID_ARM_ACTION_GET_ACTION_LIST, "{}")?;
+        decode(&data)
+    }
+}
+
+impl Default for ArmActionClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rpc::rpc_error::ROBOT_ERR_CLIENT_API_DATA;
+
+    #[test]
+    fn action_list_is_returned_verbatim() {
+        let reply = r#"{"data":[{"id":11,"name":"two-hand kiss"}],"extra":true}"#;
+        let list: Value = decode(reply).unwrap();
+        assert_eq!(list["data"][0]["id"], 11);
+        assert_eq!(list["extra"], true);
+        assert_eq!(decode::<Value>("not json"), Err(ROBOT_ERR_CLIENT_API_DATA));
+    }
+}
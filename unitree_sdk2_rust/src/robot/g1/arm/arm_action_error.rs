@@ -0,0 +1,9 @@
+//! G1 arm action service error codes.
+
+/// The topic `rt/arm_sdk` is occupied.
+pub const ROBOT_ARM_ACTION_ERR_ARMSDK: i32 = 7400;
+/// The arm is holding; send release (99) or the same action id first.
+pub const ROBOT_ARM_ACTION_ERR_HOLDING: i32 = 7401;
+pub const ROBOT_ARM_ACTION_ERR_INVALID_ACTION_ID: i32 = 7402;
+/// Actions are only supported in fsm id 500, 501 and 801 (fsm mode 0 or 3).
+pub const ROBOT_ARM_ACTION_ERR_INVALID_FSM_ID: i32 = 7404;
@@ -0,0 +1,6 @@
+//! Arm action client for G1.
+pub mod arm_action_api;
+pub mod arm_action_client;
+pub mod arm_action_error;
+
+pub use arm_action_client::{ArmAction, ArmActionClient};
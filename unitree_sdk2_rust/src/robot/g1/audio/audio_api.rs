@@ -0,0 +1,25 @@
+//! Audio service name, version, method ID and format constants.
+
+use crate::audio::WavFormat;
+
+pub const AUDIO_SERVICE_NAME: &str = "voice";
+pub const AUDIO_API_VERSION: &str = "1.0.0.0";
+
+pub const ROBOT_API_ID_AUDIO_TTS: i32 = 1001;
+pub const ROBOT_API_ID_AUDIO_ASR: i32 = 1002;
+pub const ROBOT_API_ID_AUDIO_START_PLAY: i32 = 1003;
+pub const ROBOT_API_ID_AUDIO_STOP_PLAY: i32 = 1004;
+pub const ROBOT_API_ID_AUDIO_GET_VOLUME: i32 = 1005;
+pub const ROBOT_API_ID_AUDIO_SET_VOLUME: i32 = 1006;
+pub const ROBOT_API_ID_AUDIO_SET_RGB_LED: i32 = 1010;
+
+/// Topic carrying speech recognition results.
+pub const AUDIO_SUBSCRIBE_TOPIC: &str = "rt/audio_msg";
+
+/// PCM format accepted by `START_PLAY`: 16 kHz, mono, 16-bit little endian.
+pub const AUDIO_SAMPLE_RATE: u32 = WavFormat::ROBOT.sample_rate;
+pub const AUDIO_NUM_CHANNELS: u16 = WavFormat::ROBOT.num_channels;
+pub const AUDIO_BITS_PER_SAMPLE: u16 = WavFormat::ROBOT.bits_per_sample;
+
+/// Bytes of PCM sent per `START_PLAY` call (3 seconds).
+pub const AUDIO_PLAY_CHUNK_SIZE: usize = 96000;
@@ -0,0 +1,168 @@
+//! AudioClient - text-to-speech, PCM playback, volume and RGB LED control.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::audio::wav::new_stream_id;
+use crate::audio::{PcmChunks, WavFormat};
+use crate::channel::{ChannelFactory, ChannelSubscriber};
+use crate::error::Result as SdkResult;
+use crate::idl::ros2::StringMsg;
+use crate::rpc::{decode, RpcClient};
+use super::audio_api::*;
+
+/// Pause between `START_PLAY` chunks when streaming a whole buffer.
+const PLAY_CHUNK_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Serialize)]
+struct TtsMakerParameter<'a> {
+    index: u32,
+    speaker_id: u16,
+    text: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+struct PlayStreamParameter<'a> {
+    app_name: &'a str,
+    stream_id: &'a str,
+}
+
+#[derive(Debug, Deserialize)]
+struct VolumeData {
+    volume: u8,
+}
+
+/// Client for the `voice` audio service on G1 and A2.
+pub struct AudioClient {
+    rpc: RpcClient,
+    tts_index: u32,
+}
+
+impl AudioClient {
+    /// Create a new `AudioClient`.
+    pub fn new() -> Self {
+        Self {
+            rpc: RpcClient::new(AUDIO_SERVICE_NAME, false),
+            tts_index: 0,
+        }
+    }
+
+    /// Set the RPC timeout in seconds.
+    pub fn set_timeout(&mut self, timeout: f32) {
+        self.rpc.set_timeout(timeout);
+    }
+
+    /// Initialize the client.
+    pub fn init(&mut self) {
+        self.rpc.set_api_version(AUDIO_API_VERSION);
+        for api_id in [
+            ROBOT_API_ID_AUDIO_TTS,
+            ROBOT_API_ID_AUDIO_ASR,
+            ROBOT_API_ID_AUDIO_START_PLAY,
+            ROBOT_API_ID_AUDIO_STOP_PLAY,
+            ROBOT_API_ID_AUDIO_GET_VOLUME,
+            ROBOT_API_ID_AUDIO_SET_VOLUME,
+            ROBOT_API_ID_AUDIO_SET_RGB_LED,
+        ] {
+            self.rpc.register_api(api_id, 0);
+        }
+        log::info!(
+            "AudioClient init: service={} version={}",
+            AUDIO_SERVICE_NAME,
+            AUDIO_API_VERSION
+        );
+    }
+
+    /// Speak `text` (0: Chinese voice, 1: English voice). Playback starts
+    /// automatically.
+    pub fn tts_maker(&mut self, text: &str, speaker_id: u16) -> i32 {
+        let param = TtsMakerParameter {
+            index: self.tts_index,
+            speaker_id,
+            text,
+        };
+        self.tts_index = self.tts_index.wrapping_add(1);
+        let p = serde_json::to_string(&param).unwrap_or_default();
+        self.rpc.call_no_data(ROBOT_API_ID_AUDIO_TTS, &p)
+    }
+
+    /// Get the speaker volume (0-100). Returns `Ok(volume)` or `Err(api_error_code)`.
+    pub fn get_volume(&self) -> Result<u8, i32> {
+        let data = self.rpc.call(ROBOT_API_ID_AUDIO_GET_VOLUME, "{}")?;
+        Ok(decode::<VolumeData>(&data)?.volume)
+    }
+
+    /// Set the speaker volume (0-100).
+    pub fn set_volume(&self, volume: u8) -> i32 {
+        let p = format!(r#"{{"volume":{volume}}}"#);
+        self.rpc.call_no_data(ROBOT_API_ID_AUDIO_SET_VOLUME, &p)
+    }
+
+    /// Send one chunk of 16 kHz mono 16-bit PCM for playback. Chunks sharing
+    /// a `stream_id` are played back to back.
+    pub fn play_stream(&self, app_name: &str, stream_id: &str, pcm: &[u8]) -> i32 {
+        let p = serde_json::to_string(&PlayStreamParameter { app_name, stream_id })
+            .unwrap_or_default();
+        match self.rpc.call_with_binary(ROBOT_API_ID_AUDIO_START_PLAY, &p, pcm) {
+            Ok(_) => 0,
+            Err(code) => code,
+        }
+    }
+
+    /// Stop playback for `app_name`.
+    pub fn play_stop(&self, app_name: &str) -> i32 {
+        let p = serde_json::json!({ "app_name": app_name }).to_string();
+        self.rpc.call_no_data(ROBOT_API_ID_AUDIO_STOP_PLAY, &p)
+    }
+
+    /// Stream a whole PCM buffer in `AUDIO_PLAY_CHUNK_SIZE` chunks under a
+    /// fresh stream id, then stop playback. Returns the first error code.
+    pub fn play_pcm(&self, app_name: &str, pcm: &[u8]) -> i32 {
+        let stream_id = new_stream_id();
+        let chunks = PcmChunks::new(&stream_id, pcm, WavFormat::ROBOT, AUDIO_PLAY_CHUNK_SIZE);
+
+        for chunk in chunks {
+            let ret = self.play_stream(app_name, chunk.stream_id, chunk.data);
+            if ret != 0 {
+                log::error!("AudioClient play_pcm: chunk {} failed: {}", chunk.index, ret);
+                return ret;
+            }
+            log::debug!(
+                "AudioClient play_pcm: sent chunk {} ({} bytes)",
+                chunk.index,
+                chunk.data.len()
+            );
+            std::thread::sleep(PLAY_CHUNK_INTERVAL);
+        }
+
+        self.play_stop(app_name)
+    }
+
+    /// Set the RGB LED color.
+    pub fn led_control(&self, r: u8, g: u8, b: u8) -> i32 {
+        let p = format!(r#"{{"R":{r},"G":{g},"B":{b}}}"#);
+        self.rpc.call_no_data(ROBOT_API_ID_AUDIO_SET_RGB_LED, &p)
+    }
+}
+
+impl Default for AudioClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Subscribe to speech recognition results on `rt/audio_msg`.
+///
+/// `handler` receives the raw message text. Keep the returned subscriber
+/// alive for as long as results should be delivered.
+pub fn subscribe_asr(
+    handler: impl Fn(&str) + Send + 'static,
+) -> SdkResult<ChannelSubscriber<StringMsg>> {
+    let mut subscriber = ChannelFactory::instance()
+        .lock()
+        .unwrap()
+        .create_subscriber(AUDIO_SUBSCRIBE_TOPIC);
+    subscriber.init_channel(move |msg: &StringMsg| handler(&msg.data))?;
+    Ok(subscriber)
+}
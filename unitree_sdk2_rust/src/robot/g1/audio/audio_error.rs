@@ -0,0 +1,3 @@
+//! Audio service error codes.
+
+pub const ROBOT_AUDIO_ERR_COMM: i32 = 100;
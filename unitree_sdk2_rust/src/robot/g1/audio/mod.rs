@@ -0,0 +1,6 @@
+//! Audio client for G1, also used by A2.
+pub mod audio_api;
+pub mod audio_client;
+pub mod audio_error;
+
+pub use audio_client::{subscribe_asr, AudioClient};
@@ -0,0 +1,377 @@
+//! Dex3Hand - joint commands and tactile readouts for the G1 Dex3 hands.
+//!
+//! Each hand takes `HandCmd` on `rt/dex3/<side>/cmd` and reports `HandState`
+//! on `rt/dex3/<side>/state`, or at a lower rate on
+//! `rt/lf/dex3/<side>/state`.
+
+use std::sync::{Arc, Mutex};
+
+use crate::channel::{ChannelFactory, ChannelPublisher, ChannelSubscriber};
+use crate::error::Result;
+use crate::idl::hg::{HandCmd, HandState, MotorCmd};
+
+/// Number of motors in a Dex3 hand.
+pub const DEX3_NUM_MOTORS: usize = 7;
+/// Number of tactile sensor pads in a Dex3 hand.
+pub const DEX3_NUM_PRESS_SENSORS: usize = 9;
+
+/// Motor status value that enables a Dex3 motor.
+const RIS_STATUS_ENABLE: u8 = 0x01;
+
+/// Which hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HandSide {
+    Left,
+    Right,
+}
+
+impl HandSide {
+    /// `"left"` or `"right"`, as used in topic names.
+    pub fn name(self) -> &'static str {
+        match self {
+            HandSide::Left => "left",
+            HandSide::Right => "right",
+        }
+    }
+
+    /// Command topic, e.g. `rt/dex3/left/cmd`.
+    pub fn cmd_topic(self) -> String {
+        format!("rt/dex3/{}/cmd", self.name())
+    }
+
+    /// State topic; the low-frequency one is `rt/lf/dex3/<side>/state`.
+    pub fn state_topic(self, low_frequency: bool) -> String {
+        if low_frequency {
+            format!("rt/lf/dex3/{}/state", self.name())
+        } else {
+            format!("rt/dex3/{}/state", self.name())
+        }
+    }
+}
+
+/// Dex3 joints, in motor order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(usize)]
+pub enum Dex3Joint {
+    Thumb0 = 0,
+    Thumb1 = 1,
+    Thumb2 = 2,
+    Middle0 = 3,
+    Middle1 = 4,
+    Index0 = 5,
+    Index1 = 6,
+}
+
+impl Dex3Joint {
+    pub const ALL: [Dex3Joint; DEX3_NUM_MOTORS] = [
+        Dex3Joint::Thumb0,
+        Dex3Joint::Thumb1,
+        Dex3Joint::Thumb2,
+        Dex3Joint::Middle0,
+        Dex3Joint::Middle1,
+        Dex3Joint::Index0,
+        Dex3Joint::Index1,
+    ];
+
+    /// Motor index in `HandCmd`/`HandState`.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Position limits `(min, max)` in radians for this joint on `side`.
+    pub fn limits(self, side: HandSide) -> (f32, f32) {
+        let (min, max) = match side {
+            HandSide::Left => (&LEFT_MIN, &LEFT_MAX),
+            HandSide::Right => (&RIGHT_MIN, &RIGHT_MAX),
+        };
+        (min[self.index()], max[self.index()])
+    }
+}
+
+impl TryFrom<usize> for Dex3Joint {
+    type Error = usize;
+
+    fn try_from(value: usize) -> std::result::Result<Self, Self::Error> {
+        Dex3Joint::ALL.get(value).copied().ok_or(value)
+    }
+}
+
+const LEFT_MAX: [f32; DEX3_NUM_MOTORS] = [1.05, 1.05, 1.75, 0.0, 0.0, 0.0, 0.0];
+const LEFT_MIN: [f32; DEX3_NUM_MOTORS] = [-1.05, -0.724, 0.0, -1.57, -1.75, -1.57, -1.75];
+const RIGHT_MAX: [f32; DEX3_NUM_MOTORS] = [1.05, 0.742, 0.0, 1.57, 1.75, 1.57, 1.75];
+const RIGHT_MIN: [f32; DEX3_NUM_MOTORS] = [-1.05, -1.05, -1.75, 0.0, 0.0, 0.0, 0.0];
+
+/// Dex3 fingers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Finger {
+    Thumb,
+    Middle,
+    Index,
+}
+
+impl Finger {
+    /// Joints of this finger, from the palm outwards.
+    pub fn joints(self) -> &'static [Dex3Joint] {
+        match self {
+            Finger::Thumb => &[Dex3Joint::Thumb0, Dex3Joint::Thumb1, Dex3Joint::Thumb2],
+            Finger::Middle => &[Dex3Joint::Middle0, Dex3Joint::Middle1],
+            Finger::Index => &[Dex3Joint::Index0, Dex3Joint::Index1],
+        }
+    }
+}
+
+/// Whole-hand poses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GripPreset {
+    /// Every joint at zero: fingers straight.
+    Open,
+    /// Every joint at the middle of its range, as in the C++ example grip.
+    Close,
+}
+
+impl GripPreset {
+    /// Joint targets for `side`.
+    pub fn positions(self, side: HandSide) -> [f32; DEX3_NUM_MOTORS] {
+        match self {
+            GripPreset::Open => [0.0; DEX3_NUM_MOTORS],
+            GripPreset::Close => Dex3Joint::ALL.map(|j| {
+                let (min, max) = j.limits(side);
+                (min + max) / 2.0
+            }),
+        }
+    }
+}
+
+/// Pack a Dex3 motor mode byte: id in bits 0-3, status in bits 4-6,
+/// timeout in bit 7.
+pub fn ris_mode(id: u8, status: u8, timeout: bool) -> u8 {
+    (id & 0x0F) | ((status & 0x07) << 4) | ((timeout as u8) << 7)
+}
+
+/// One Dex3 hand: owns its command publisher and state subscriber.
+pub struct Dex3Hand {
+    side: HandSide,
+    cmd: HandCmd,
+    latest: Arc<Mutex<Option<HandState>>>,
+    publisher: ChannelPublisher<HandCmd>,
+    subscriber: ChannelSubscriber<HandState>,
+}
+
+impl Dex3Hand {
+    /// Create the channels for `side`. `low_frequency` selects the
+    /// `rt/lf/...` state topic.
+    pub fn new(side: HandSide, low_frequency: bool) -> Result<Self> {
+        let factory = ChannelFactory::instance().lock().unwrap();
+        let mut publisher: ChannelPublisher<HandCmd> = factory.create_publisher(&side.cmd_topic());
+        let mut subscriber: ChannelSubscriber<HandState> =
+            factory.create_subscriber(&side.state_topic(low_frequency));
+        drop(factory);
+
+        publisher.init_channel()?;
+        let latest = Arc::new(Mutex::new(None));
+        {
+            let latest = Arc::clone(&latest);
+            subscriber.init_channel(move |state: &HandState| {
+                *latest.lock().unwrap() = Some(state.clone());
+            })?;
+        }
+
+        let cmd = HandCmd {
+            motor_cmd: (0..DEX3_NUM_MOTORS)
+                .map(|id| MotorCmd {
+                    mode: ris_mode(id as u8, RIS_STATUS_ENABLE, false),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        };
+
+        Ok(Self {
+            side,
+            cmd,
+            latest,
+            publisher,
+            subscriber,
+        })
+    }
+
+    /// Which hand this is.
+    pub fn side(&self) -> HandSide {
+        self.side
+    }
+
+    /// The command built so far.
+    pub fn cmd(&self) -> &HandCmd {
+        &self.cmd
+    }
+
+    /// Set one joint's target; `q` is clamped to the joint limits. Takes
+    /// effect on the next [`Dex3Hand::send`].
+    pub fn set_joint(&mut self, joint: Dex3Joint, q: f32, kp: f32, kd: f32) {
+        let (min, max) = joint.limits(self.side);
+        let m = &mut self.cmd.motor_cmd[joint.index()];
+        m.mode = ris_mode(joint.index() as u8, RIS_STATUS_ENABLE, false);
+        m.q = q.clamp(min, max);
+        m.dq = 0.0;
+        m.tau = 0.0;
+        m.kp = kp;
+        m.kd = kd;
+    }
+
+    /// Set the joints of one finger, from the palm outwards. Extra values
+    /// are ignored.
+    pub fn set_finger(&mut self, finger: Finger, q: &[f32], kp: f32, kd: f32) {
+        for (&joint, &q) in finger.joints().iter().zip(q) {
+            self.set_joint(joint, q, kp, kd);
+        }
+    }
+
+    /// Set every joint, in motor order.
+    pub fn set_positions(&mut self, q: [f32; DEX3_NUM_MOTORS], kp: f32, kd: f32) {
+        for (joint, q) in Dex3Joint::ALL.into_iter().zip(q) {
+            self.set_joint(joint, q, kp, kd);
+        }
+    }
+
+    /// Set every joint to a preset pose.
+    pub fn set_preset(&mut self, preset: GripPreset, kp: f32, kd: f32) {
+        self.set_positions(preset.positions(self.side), kp, kd);
+    }
+
+    /// Publish the current command.
+    pub fn send(&mut self) -> Result<()> {
+        self.publisher.write(&self.cmd)?;
+        Ok(())
+    }
+
+    /// Open the hand and publish.
+    pub fn open(&mut self, kp: f32, kd: f32) -> Result<()> {
+        self.set_preset(GripPreset::Open, kp, kd);
+        self.send()
+    }
+
+    /// Close the hand and publish.
+    pub fn close(&mut self, kp: f32, kd: f32) -> Result<()> {
+        self.set_preset(GripPreset::Close, kp, kd);
+        self.send()
+    }
+
+    /// Release every motor (zero gains, timeout bit set) and publish.
+    pub fn stop(&mut self) -> Result<()> {
+        for (id, m) in self.cmd.motor_cmd.iter_mut().enumerate() {
+            *m = MotorCmd {
+                mode: ris_mode(id as u8, RIS_STATUS_ENABLE, true),
+                ..Default::default()
+            };
+        }
+        self.send()
+    }
+
+    /// Latest `HandState` received, if any.
+    pub fn state(&self) -> Option<HandState> {
+        self.latest.lock().unwrap().clone()
+    }
+
+    /// Measured joint positions, in motor order.
+    pub fn positions(&self) -> Option<[f32; DEX3_NUM_MOTORS]> {
+        let state = self.latest.lock().unwrap();
+        let motors = &state.as_ref()?.motor_state;
+        (motors.len() >= DEX3_NUM_MOTORS).then(|| std::array::from_fn(|i| motors[i].q))
+    }
+
+    /// Joint positions scaled to [0, 1] over each joint's limits.
+    pub fn normalized_positions(&self) -> Option<[f32; DEX3_NUM_MOTORS]> {
+        let q = self.positions()?;
+        Some(Dex3Joint::ALL.map(|j| {
+            let (min, max) = j.limits(self.side);
+            ((q[j.index()] - min) / (max - min)).clamp(0.0, 1.0)
+        }))
+    }
+
+    /// Raw pressure readings, one array per tactile sensor pad.
+    pub fn pressures(&self) -> Option<Vec<[f32; 12]>> {
+        let state = self.latest.lock().unwrap();
+        Some(state.as_ref()?.press_sensor_state.iter().map(|s| s.pressure).collect())
+    }
+
+    /// Sum of all pressure readings of one sensor pad.
+    pub fn pad_pressure(&self, pad: usize) -> Option<f32> {
+        let state = self.latest.lock().unwrap();
+        let pad = state.as_ref()?.press_sensor_state.get(pad)?;
+        Some(pad.pressure.iter().sum())
+    }
+}
+
+impl Drop for Dex3Hand {
+    fn drop(&mut self) {
+        self.subscriber.close_channel();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::idl::hg::MotorState;
+
+    fn hand_at(side: HandSide, q: [f32; DEX3_NUM_MOTORS]) -> Dex3Hand {
+        let hand = Dex3Hand::new(side, false).unwrap();
+        *hand.latest.lock().unwrap() = Some(HandState {
+            motor_state: q.iter().map(|&q| MotorState { q, ..Default::default() }).collect(),
+            ..Default::default()
+        });
+        hand
+    }
+
+    #[test]
+    fn ris_mode_packs_id_status_and_timeout() {
+        assert_eq!(ris_mode(3, 1, true), 0x93);
+        assert_eq!(ris_mode(6, 1, false), 0x16);
+        assert_eq!(ris_mode(0, 0, true), 0x80);
+        // Out-of-range values do not spill into the neighbouring fields.
+        assert_eq!(ris_mode(0x1F, 0x0F, false), 0x7F);
+    }
+
+    #[test]
+    fn set_joint_clamps_to_the_side_limits() {
+        let mut left = Dex3Hand::new(HandSide::Left, false).unwrap();
+        left.set_joint(Dex3Joint::Thumb1, -2.0, 1.5, 0.1);
+        left.set_joint(Dex3Joint::Thumb2, 2.0, 1.5, 0.1);
+        left.set_joint(Dex3Joint::Index0, 1.0, 1.5, 0.1);
+        let m = &left.cmd().motor_cmd;
+        assert_eq!((m[1].q, m[2].q, m[5].q), (-0.724, 1.75, 0.0));
+        assert_eq!((m[1].kp, m[1].kd, m[1].mode), (1.5, 0.1, ris_mode(1, 1, false)));
+
+        let mut right = Dex3Hand::new(HandSide::Right, false).unwrap();
+        right.set_joint(Dex3Joint::Thumb1, 2.0, 1.5, 0.1);
+        right.set_joint(Dex3Joint::Thumb2, 2.0, 1.5, 0.1);
+        right.set_joint(Dex3Joint::Index0, -1.0, 1.5, 0.1);
+        right.set_joint(Dex3Joint::Middle1, 1.0, 1.5, 0.1);
+        let m = &right.cmd().motor_cmd;
+        assert_eq!((m[1].q, m[2].q, m[5].q, m[4].q), (0.742, 0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn close_is_the_midpoint_of_each_side() {
+        let expected = [
+            (HandSide::Left, [0.0, 0.163, 0.875, -0.785, -0.875, -0.785, -0.875]),
+            (HandSide::Right, [0.0, -0.154, -0.875, 0.785, 0.875, 0.785, 0.875]),
+        ];
+        for (side, midpoint) in expected {
+            let close = GripPreset::Close.positions(side);
+            assert!(close.iter().zip(midpoint).all(|(a, b)| (a - b).abs() < 1e-6), "{side:?}: {close:?}");
+        }
+        assert_eq!(GripPreset::Open.positions(HandSide::Right), [0.0; DEX3_NUM_MOTORS]);
+    }
+
+    #[test]
+    fn normalized_positions_span_the_limits() {
+        assert_eq!(hand_at(HandSide::Left, LEFT_MIN).normalized_positions(), Some([0.0; DEX3_NUM_MOTORS]));
+        assert_eq!(hand_at(HandSide::Left, LEFT_MAX).normalized_positions(), Some([1.0; DEX3_NUM_MOTORS]));
+        assert_eq!(hand_at(HandSide::Right, RIGHT_MAX).normalized_positions(), Some([1.0; DEX3_NUM_MOTORS]));
+        // Readings past a limit are clamped.
+        let q = hand_at(HandSide::Right, [-5.0; DEX3_NUM_MOTORS]).normalized_positions().unwrap();
+        assert_eq!(q, [0.0; DEX3_NUM_MOTORS]);
+
+        assert_eq!(Dex3Hand::new(HandSide::Left, false).unwrap().normalized_positions(), None);
+    }
+}
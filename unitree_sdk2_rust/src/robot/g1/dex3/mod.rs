@@ -0,0 +1,4 @@
+//! Dex3 dexterous hands for G1.
+pub mod dex3_hand;
+
+pub use dex3_hand::{Dex3Hand, Dex3Joint, Finger, GripPreset, HandSide};
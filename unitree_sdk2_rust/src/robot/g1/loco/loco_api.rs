@@ -0,0 +1,29 @@
+//! G1 locomotion service name, version, method ID and FSM ID constants.
+
+pub const LOCO_SERVICE_NAME: &str = "sport";
+pub const LOCO_API_VERSION: &str = "1.0.0.0";
+
+pub const ROBOT_API_ID_LOCO_GET_FSM_ID: i32 = 7001;
+pub const ROBOT_API_ID_LOCO_GET_FSM_MODE: i32 = 7002;
+pub const ROBOT_API_ID_LOCO_GET_BALANCE_MODE: i32 = 7003;
+pub const ROBOT_API_ID_LOCO_GET_SWING_HEIGHT: i32 = 7004;
+pub const ROBOT_API_ID_LOCO_GET_STAND_HEIGHT: i32 = 7005;
+/// Deprecated by the robot firmware.
+pub const ROBOT_API_ID_LOCO_GET_PHASE: i32 = 7006;
+pub const ROBOT_API_ID_LOCO_SET_FSM_ID: i32 = 7101;
+pub const ROBOT_API_ID_LOCO_SET_BALANCE_MODE: i32 = 7102;
+pub const ROBOT_API_ID_LOCO_SET_SWING_HEIGHT: i32 = 7103;
+pub const ROBOT_API_ID_LOCO_SET_STAND_HEIGHT: i32 = 7104;
+pub const ROBOT_API_ID_LOCO_SET_VELOCITY: i32 = 7105;
+pub const ROBOT_API_ID_LOCO_SET_ARM_TASK: i32 = 7106;
+pub const ROBOT_API_ID_LOCO_SET_SPEED_MODE: i32 = 7107;
+
+pub const LOCO_FSM_ID_ZERO_TORQUE: i32 = 0;
+pub const LOCO_FSM_ID_DAMP: i32 = 1;
+pub const LOCO_FSM_ID_SQUAT: i32 = 2;
+pub const LOCO_FSM_ID_SIT: i32 = 3;
+pub const LOCO_FSM_ID_STAND_UP: i32 = 4;
+pub const LOCO_FSM_ID_START: i32 = 500;
+
+/// Velocity command duration used for continuous moves (10 days), in seconds.
+pub const LOCO_CONTINUOUS_MOVE_DURATION: f32 = 864000.0;
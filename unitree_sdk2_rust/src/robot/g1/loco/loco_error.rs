@@ -0,0 +1,5 @@
+//! G1 locomotion service error codes.
+
+pub const ROBOT_LOCO_ERR_LOCOSTATE_NOT_AVAILABLE: i32 = 7301;
+pub const ROBOT_LOCO_ERR_INVALID_FSM_ID: i32 = 7302;
+pub const ROBOT_LOCO_ERR_INVALID_TASK_ID: i32 = 7303;
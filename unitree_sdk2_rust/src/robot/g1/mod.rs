@@ -0,0 +1,6 @@
+//! G1 humanoid clients.
+pub mod agv;
+pub mod arm;
+pub mod audio;
+pub mod dex3;
+pub mod loco;
@@ -1,3 +1,4 @@
 //! Go2 robot clients.
 pub mod sport;
 pub mod robot_state;
+pub mod video;
@@ -1,5 +1,7 @@
 //! Robot state client for Go2.
 pub mod robot_state_api;
 pub mod robot_state_client;
+pub mod robot_state_error;
 
 pub use robot_state_client::{RobotStateClient, ServiceState};
+pub use robot_state_error::RobotStateError;
@@ -3,6 +3,6 @@
 pub const ROBOT_STATE_SERVICE_NAME: &str = "robot_state";
 pub const ROBOT_STATE_API_VERSION: &str = "1.0.0.1";
 
-pub const ROBOT_STATE_API_ID_SERVICE_LIST: i32 = 1;
-pub const ROBOT_STATE_API_ID_SERVICE_SWITCH: i32 = 2;
-pub const ROBOT_STATE_API_ID_SET_REPORT_FREQ: i32 = 3;
+pub const ROBOT_STATE_API_ID_SERVICE_SWITCH: i32 = 1001;
+pub const ROBOT_STATE_API_ID_SET_REPORT_FREQ: i32 = 1002;
+pub const ROBOT_STATE_API_ID_SERVICE_LIST: i32 = 1003;
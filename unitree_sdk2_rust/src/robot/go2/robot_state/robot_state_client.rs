@@ -1,30 +1,54 @@
 //! RobotStateClient - manages robot service states.
-//!
-//! TODO: Real implementation would use DDS RPC over CycloneDDS.
 
+use serde::{Deserialize, Serialize};
+
+use crate::rpc::{decode, JsonData, RpcClient};
 use super::robot_state_api::*;
+use super::robot_state_error::RobotStateError;
 
 /// Describes a robot service with its name, status, and protection flag.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct ServiceState {
     pub name: String,
     pub status: i32,
     pub protect: i32,
 }
 
+/// Response data of a service switch.
+#[derive(Debug, Deserialize)]
+struct ServiceSwitchData {
+    status: i32,
+}
+
+/// Decode a `{"data":[...]}` service list reply.
+fn decode_service_list(data: &str) -> Result<Vec<ServiceState>, RobotStateError> {
+    Ok(decode::<JsonData<Vec<ServiceState>>>(data)?.data)
+}
+
 /// Client for querying and controlling robot service states.
 pub struct RobotStateClient {
-    timeout: f32,
+    rpc: RpcClient,
 }
 
 impl RobotStateClient {
     /// Create a new `RobotStateClient`.
     pub fn new() -> Self {
-        Self { timeout: 10.0 }
+        Self {
+            rpc: RpcClient::new(ROBOT_STATE_SERVICE_NAME, false),
+        }
+    }
+
+    /// Set the RPC timeout in seconds.
+    pub fn set_timeout(&mut self, timeout: f32) {
+        self.rpc.set_timeout(timeout);
     }
 
     /// Initialize the client.
     pub fn init(&mut self) {
+        self.rpc.set_api_version(ROBOT_STATE_API_VERSION);
+        self.rpc.register_api(ROBOT_STATE_API_ID_SERVICE_SWITCH, 0);
+        self.rpc.register_api(ROBOT_STATE_API_ID_SET_REPORT_FREQ, 0);
+        self.rpc.register_api(ROBOT_STATE_API_ID_SERVICE_LIST, 0);
         log::info!(
             "RobotStateClient init: service={} version={}",
             ROBOT_STATE_SERVICE_NAME,
@@ -33,33 +57,24 @@ impl RobotStateClient {
     }
 
     /// List all services on the robot.
-    pub fn service_list(&self) -> Result<Vec<ServiceState>, i32> {
-        log::info!("RobotStateClient service_list: api_id={}", ROBOT_STATE_API_ID_SERVICE_LIST);
-        // Stub: return an empty list
-        Ok(vec![])
+    pub fn service_list(&self) -> Result<Vec<ServiceState>, RobotStateError> {
+        let data = self.rpc.call(ROBOT_STATE_API_ID_SERVICE_LIST, "{}")?;
+        decode_service_list(&data)
     }
 
-    /// Switch a service on or off.
-    pub fn service_switch(&self, name: &str, swit: i32) -> Result<i32, i32> {
-        log::info!(
-            "RobotStateClient service_switch: api_id={} name={} switch={}",
-            ROBOT_STATE_API_ID_SERVICE_SWITCH,
-            name,
-            swit
-        );
-        Ok(0)
+    /// Switch a service on (`1`) or off (`0`). Returns the resulting status.
+    pub fn service_switch(&self, name: &str, swit: i32) -> Result<i32, RobotStateError> {
+        let p = serde_json::json!({ "name": name, "switch": swit }).to_string();
+        let data = self.rpc.call(ROBOT_STATE_API_ID_SERVICE_SWITCH, &p)?;
+        let resp: ServiceSwitchData = decode(&data)?;
+        Ok(resp.status)
     }
 
-    /// Set report frequency.
-    pub fn set_report_freq(&self, interval: i32, duration: i32) -> i32 {
-        log::info!(
-            "RobotStateClient set_report_freq: api_id={} interval={} duration={}",
-            ROBOT_STATE_API_ID_SET_REPORT_FREQ,
-            interval,
-            duration
-        );
-        let _ = self.timeout;
-        0
+    /// Set the state report interval and duration, in seconds.
+    pub fn set_report_freq(&self, interval: i32, duration: i32) -> Result<(), RobotStateError> {
+        let p = format!(r#"{{"interval":{interval},"duration":{duration}}}"#);
+        self.rpc.call(ROBOT_STATE_API_ID_SET_REPORT_FREQ, &p)?;
+        Ok(())
     }
 }
 
@@ -68,3 +83,29 @@ impl Default for RobotStateClient {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rpc::rpc_error::ROBOT_ERR_CLIENT_API_DATA;
+
+    #[test]
+    fn service_list_is_decoded_from_data() {
+        let reply = r#"{"data":[{"name":"sport_mode","status":0,"protect":1},{"name":"obstacles_avoid","status":1,"protect":0}]}"#;
+        assert_eq!(
+            decode_service_list(reply).unwrap(),
+            [
+                ServiceState { name: "sport_mode".to_string(), status: 0, protect: 1 },
+                ServiceState { name: "obstacles_avoid".to_string(), status: 1, protect: 0 },
+            ]
+        );
+        assert_eq!(decode_service_list(r#"{"data":[]}"#).unwrap(), []);
+    }
+
+    #[test]
+    fn malformed_service_list_is_a_data_error() {
+        for reply in ["{}", r#"[{"name":"sport_mode","status":0,"protect":1}]"#, r#"{"data":[{"name":1}]}"#] {
+            assert_eq!(decode_service_list(reply), Err(RobotStateError::Api(ROBOT_ERR_CLIENT_API_DATA)));
+        }
+    }
+}
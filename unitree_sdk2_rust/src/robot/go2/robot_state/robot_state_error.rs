@@ -0,0 +1,39 @@
+//! Robot state service errors.
+use thiserror::Error;
+
+pub const ROBOT_STATE_CLIENT_ERR_SERVICE_SWITCH: i32 = 5201;
+pub const ROBOT_STATE_CLIENT_ERR_SERVICE_PROTECTED: i32 = 5202;
+
+/// Error returned by `RobotStateClient` calls.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum RobotStateError {
+    #[error("Service switch error")]
+    ServiceSwitch,
+    #[error("Service is protected")]
+    ServiceProtected,
+    #[error("API error code: {0}")]
+    Api(i32),
+}
+
+impl From<i32> for RobotStateError {
+    fn from(code: i32) -> Self {
+        match code {
+            ROBOT_STATE_CLIENT_ERR_SERVICE_SWITCH => Self::ServiceSwitch,
+            ROBOT_STATE_CLIENT_ERR_SERVICE_PROTECTED => Self::ServiceProtected,
+            other => Self::Api(other),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codes_map_to_errors() {
+        assert_eq!(RobotStateError::from(5201), RobotStateError::ServiceSwitch);
+        assert_eq!(RobotStateError::from(5202), RobotStateError::ServiceProtected);
+        assert_eq!(RobotStateError::from(3104), RobotStateError::Api(3104));
+        assert_eq!(RobotStateError::from(-1), RobotStateError::Api(-1));
+    }
+}
@@ -1,5 +1,12 @@
 //! Sport mode client for Go2.
+pub mod mode_tracker;
 pub mod sport_api;
 pub mod sport_client;
+pub mod sport_error;
+pub mod sport_wait;
+pub mod trajectory;
 
+pub use mode_tracker::{ModeEvent, ModeTracker};
 pub use sport_client::SportClient;
+pub use sport_wait::{SportStateWatcher, SportTransition};
+pub use trajectory::{encode_path, sample_path, validate_path};
@@ -0,0 +1,138 @@
+//! ModeTracker - detects sport mode and gait transitions from `SportModeState`.
+
+use crate::idl::go2::{GaitType, SportMode, SportModeState};
+
+/// A change observed between two consecutive `SportModeState` samples.
+///
+/// `from` is `None` for the first known value seen by the tracker, and for
+/// the first known value after an unknown one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModeEvent {
+    ModeChanged { from: Option<SportMode>, to: SportMode },
+    GaitChanged { from: Option<GaitType>, to: GaitType },
+    /// The robot reported a mode value this SDK does not know.
+    UnknownMode(u8),
+    /// The robot reported a gait value this SDK does not know.
+    UnknownGait(u8),
+}
+
+/// Tracks the last mode and gait and reports transitions.
+///
+/// Feed every state received on `rt/sportmodestate` to [`ModeTracker::update`].
+/// Events fire when the raw value changes, so a robot sitting in an unknown
+/// mode reports it once rather than on every sample.
+#[derive(Debug, Clone, Default)]
+pub struct ModeTracker {
+    mode: Option<u8>,
+    gait: Option<u8>,
+}
+
+impl ModeTracker {
+    /// Create a tracker with no known state.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Current FSM mode, if known.
+    pub fn mode(&self) -> Option<SportMode> {
+        SportMode::try_from(self.mode?).ok()
+    }
+
+    /// Current gait type, if known.
+    pub fn gait_type(&self) -> Option<GaitType> {
+        GaitType::try_from(self.gait?).ok()
+    }
+
+    /// Update with a new state sample and return the transitions it caused.
+    pub fn update(&mut self, state: &SportModeState) -> Vec<ModeEvent> {
+        let mut events = Vec::new();
+
+        if self.mode != Some(state.mode) {
+            let from = self.mode();
+            self.mode = Some(state.mode);
+            match state.mode() {
+                Ok(mode) => {
+                    log::debug!("Sport mode changed: {:?} -> {:?}", from, mode);
+                    events.push(ModeEvent::ModeChanged { from, to: mode });
+                }
+                Err(raw) => {
+                    log::warn!("Unknown sport mode {}", raw);
+                    events.push(ModeEvent::UnknownMode(raw));
+                }
+            }
+        }
+
+        if self.gait != Some(state.gait_type) {
+            let from = self.gait_type();
+            self.gait = Some(state.gait_type);
+            match state.gait_type() {
+                Ok(gait) => {
+                    log::debug!("Gait type changed: {:?} -> {:?}", from, gait);
+                    events.push(ModeEvent::GaitChanged { from, to: gait });
+                }
+                Err(raw) => {
+                    log::warn!("Unknown gait type {}", raw);
+                    events.push(ModeEvent::UnknownGait(raw));
+                }
+            }
+        }
+
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(mode: u8, gait_type: u8) -> SportModeState {
+        SportModeState {
+            mode,
+            gait_type,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn first_update_reports_the_initial_mode() {
+        let mut tracker = ModeTracker::new();
+        assert_eq!(
+            tracker.update(&state(1, 0)),
+            [
+                ModeEvent::ModeChanged { from: None, to: SportMode::BalanceStand },
+                ModeEvent::GaitChanged { from: None, to: GaitType::Idle },
+            ]
+        );
+        assert_eq!(tracker.mode(), Some(SportMode::BalanceStand));
+        assert_eq!(tracker.gait_type(), Some(GaitType::Idle));
+    }
+
+    #[test]
+    fn repeated_states_report_nothing() {
+        let mut tracker = ModeTracker::new();
+        tracker.update(&state(1, 0));
+        assert!(tracker.update(&state(1, 0)).is_empty());
+
+        assert_eq!(
+            tracker.update(&state(1, 1)),
+            [ModeEvent::GaitChanged { from: Some(GaitType::Idle), to: GaitType::Trot }]
+        );
+        assert!(tracker.update(&state(1, 1)).is_empty());
+    }
+
+    #[test]
+    fn unknown_values_are_reported_once() {
+        let mut tracker = ModeTracker::new();
+        tracker.update(&state(1, 0));
+
+        assert_eq!(tracker.update(&state(200, 0)), [ModeEvent::UnknownMode(200)]);
+        assert_eq!(tracker.mode(), None);
+        assert!(tracker.update(&state(200, 0)).is_empty());
+        assert_eq!(tracker.update(&state(200, 250)), [ModeEvent::UnknownGait(250)]);
+
+        assert_eq!(
+            tracker.update(&state(1, 250)),
+            [ModeEvent::ModeChanged { from: None, to: SportMode::BalanceStand }]
+        );
+    }
+}
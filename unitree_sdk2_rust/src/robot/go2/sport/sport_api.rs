@@ -3,6 +3,8 @@
 pub const ROBOT_SPORT_SERVICE_NAME: &str = "sport";
 pub const ROBOT_SPORT_API_VERSION: &str = "1.0.0.1";
 
+pub const TOPIC_SPORT_MODE_STATE: &str = "rt/sportmodestate";
+
 pub const ROBOT_SPORT_API_ID_DAMP: i32 = 1001;
 pub const ROBOT_SPORT_API_ID_BALANCESTAND: i32 = 1002;
 pub const ROBOT_SPORT_API_ID_STOPMOVE: i32 = 1003;
@@ -16,6 +18,7 @@ pub const ROBOT_SPORT_API_ID_RISESIT: i32 = 1010;
 pub const ROBOT_SPORT_API_ID_SPEEDLEVEL: i32 = 1015;
 pub const ROBOT_SPORT_API_ID_HELLO: i32 = 1016;
 pub const ROBOT_SPORT_API_ID_STRETCH: i32 = 1017;
+pub const ROBOT_SPORT_API_ID_TRAJECTORYFOLLOW: i32 = 1018;
 pub const ROBOT_SPORT_API_ID_CONTENT: i32 = 1020;
 pub const ROBOT_SPORT_API_ID_DANCE1: i32 = 1022;
 pub const ROBOT_SPORT_API_ID_DANCE2: i32 = 1023;
@@ -1,256 +1,294 @@
 //! SportClient - high-level sport-mode API for the Go2 robot.
-//!
-//! TODO: Real implementation would use DDS RPC over CycloneDDS.
-//! All methods currently log their invocation and return 0 (success) as stubs.
 
+use crate::idl::go2::PathPoint;
+use crate::rpc::{decode, JsonData, RpcClient};
 use super::sport_api::*;
+use super::trajectory::encode_path;
 
 /// Controls the Go2 robot's sport/locomotion modes.
 pub struct SportClient {
-    enable_lease: bool,
-    timeout: f32,
+    rpc: RpcClient,
 }
 
 impl SportClient {
     /// Create a new `SportClient`.
     pub fn new(enable_lease: bool) -> Self {
         Self {
-            enable_lease,
-            timeout: 10.0,
+            rpc: RpcClient::new(ROBOT_SPORT_SERVICE_NAME, enable_lease),
         }
     }
 
     /// Set the RPC timeout in seconds.
     pub fn set_timeout(&mut self, timeout: f32) {
-        self.timeout = timeout;
+        self.rpc.set_timeout(timeout);
     }
 
-    /// Initialize the client (connect to DDS).
+    /// Initialize the client.
     pub fn init(&mut self) {
+        self.rpc.set_api_version(ROBOT_SPORT_API_VERSION);
+        for api_id in [
+            ROBOT_SPORT_API_ID_DAMP,
+            ROBOT_SPORT_API_ID_BALANCESTAND,
+            ROBOT_SPORT_API_ID_STOPMOVE,
+            ROBOT_SPORT_API_ID_STANDUP,
+            ROBOT_SPORT_API_ID_STANDDOWN,
+            ROBOT_SPORT_API_ID_RECOVERYSTAND,
+            ROBOT_SPORT_API_ID_EULER,
+            ROBOT_SPORT_API_ID_MOVE,
+            ROBOT_SPORT_API_ID_SIT,
+            ROBOT_SPORT_API_ID_RISESIT,
+            ROBOT_SPORT_API_ID_SPEEDLEVEL,
+            ROBOT_SPORT_API_ID_HELLO,
+            ROBOT_SPORT_API_ID_STRETCH,
+            ROBOT_SPORT_API_ID_TRAJECTORYFOLLOW,
+            ROBOT_SPORT_API_ID_CONTENT,
+            ROBOT_SPORT_API_ID_DANCE1,
+            ROBOT_SPORT_API_ID_DANCE2,
+            ROBOT_SPORT_API_ID_SWITCHJOYSTICK,
+            ROBOT_SPORT_API_ID_POSE,
+            ROBOT_SPORT_API_ID_SCRAPE,
+            ROBOT_SPORT_API_ID_FRONTFLIP,
+            ROBOT_SPORT_API_ID_FRONTJUMP,
+            ROBOT_SPORT_API_ID_FRONTPOUNCE,
+            ROBOT_SPORT_API_ID_HEART,
+            ROBOT_SPORT_API_ID_STATICWALK,
+            ROBOT_SPORT_API_ID_TROTRUN,
+            ROBOT_SPORT_API_ID_ECONOMICGAIT,
+            ROBOT_SPORT_API_ID_LEFTFLIP,
+            ROBOT_SPORT_API_ID_BACKFLIP,
+            ROBOT_SPORT_API_ID_HANDSTAND,
+            ROBOT_SPORT_API_ID_FREEWALK,
+            ROBOT_SPORT_API_ID_FREEBOUND,
+            ROBOT_SPORT_API_ID_FREEJUMP,
+            ROBOT_SPORT_API_ID_FREEAVOID,
+            ROBOT_SPORT_API_ID_CLASSICWALK,
+            ROBOT_SPORT_API_ID_WALKUPRIGHT,
+            ROBOT_SPORT_API_ID_CROSSSTEP,
+            ROBOT_SPORT_API_ID_AUTORECOVERY_SET,
+            ROBOT_SPORT_API_ID_AUTORECOVERY_GET,
+            ROBOT_SPORT_API_ID_SWITCHAVOIDMODE,
+        ] {
+            self.rpc.register_api(api_id, 0);
+        }
         log::info!(
             "SportClient init: service={} version={} enable_lease={}",
             ROBOT_SPORT_SERVICE_NAME,
             ROBOT_SPORT_API_VERSION,
-            self.enable_lease
+            self.rpc.enable_lease()
         );
     }
 
-    fn call_api(&self, api_id: i32, parameter: &str) -> i32 {
-        log::info!(
-            "SportClient call: api_id={} parameter={}",
-            api_id,
-            parameter
-        );
-        0
+    fn set_flag(&self, api_id: i32, flag: bool) -> i32 {
+        self.rpc.call_no_data(api_id, &JsonData::encode(flag))
     }
 
     /// Damp all motors.
     pub fn damp(&self) -> i32 {
-        self.call_api(ROBOT_SPORT_API_ID_DAMP, "{}")
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_DAMP, "{}")
     }
 
     /// Enter balance stand mode.
     pub fn balance_stand(&self) -> i32 {
-        self.call_api(ROBOT_SPORT_API_ID_BALANCESTAND, "{}")
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_BALANCESTAND, "{}")
     }
 
     /// Stop all movement.
     pub fn stop_move(&self) -> i32 {
-        self.call_api(ROBOT_SPORT_API_ID_STOPMOVE, "{}")
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_STOPMOVE, "{}")
     }
 
     /// Stand up.
     pub fn stand_up(&self) -> i32 {
-        self.call_api(ROBOT_SPORT_API_ID_STANDUP, "{}")
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_STANDUP, "{}")
     }
 
     /// Stand down (lie down).
     pub fn stand_down(&self) -> i32 {
-        self.call_api(ROBOT_SPORT_API_ID_STANDDOWN, "{}")
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_STANDDOWN, "{}")
     }
 
     /// Recovery stand from fallen state.
     pub fn recovery_stand(&self) -> i32 {
-        self.call_api(ROBOT_SPORT_API_ID_RECOVERYSTAND, "{}")
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_RECOVERYSTAND, "{}")
     }
 
     /// Set body orientation (Euler angles in radians).
     pub fn euler(&self, roll: f32, pitch: f32, yaw: f32) -> i32 {
         let p = format!(r#"{{"x":{roll},"y":{pitch},"z":{yaw}}}"#);
-        self.call_api(ROBOT_SPORT_API_ID_EULER, &p)
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_EULER, &p)
     }
 
     /// Move with velocity (m/s for vx/vy, rad/s for vyaw).
     pub fn move_cmd(&self, vx: f32, vy: f32, vyaw: f32) -> i32 {
         let p = format!(r#"{{"x":{vx},"y":{vy},"z":{vyaw}}}"#);
-        self.call_api(ROBOT_SPORT_API_ID_MOVE, &p)
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_MOVE, &p)
     }
 
     /// Sit down.
     pub fn sit(&self) -> i32 {
-        self.call_api(ROBOT_SPORT_API_ID_SIT, "{}")
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_SIT, "{}")
     }
 
     /// Rise from sit.
     pub fn rise_sit(&self) -> i32 {
-        self.call_api(ROBOT_SPORT_API_ID_RISESIT, "{}")
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_RISESIT, "{}")
     }
 
     /// Set speed level.
     pub fn speed_level(&self, level: i32) -> i32 {
-        let p = format!(r#"{{"value":{level}}}"#);
-        self.call_api(ROBOT_SPORT_API_ID_SPEEDLEVEL, &p)
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_SPEEDLEVEL, &JsonData::encode(level))
     }
 
     /// Hello gesture.
     pub fn hello(&self) -> i32 {
-        self.call_api(ROBOT_SPORT_API_ID_HELLO, "{}")
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_HELLO, "{}")
     }
 
     /// Stretch pose.
     pub fn stretch(&self) -> i32 {
-        self.call_api(ROBOT_SPORT_API_ID_STRETCH, "{}")
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_STRETCH, "{}")
+    }
+
+    /// Follow a time-stamped path.
+    ///
+    /// The path is validated client-side first; an invalid path returns
+    /// `ROBOT_SPORT_ERR_CLIENT_POINT_PATH` (4101) without contacting the robot.
+    pub fn trajectory_follow(&self, path: &[PathPoint]) -> i32 {
+        match encode_path(path) {
+            Ok(p) => self.rpc.call_no_data(ROBOT_SPORT_API_ID_TRAJECTORYFOLLOW, &p),
+            Err(code) => code,
+        }
     }
 
     /// Switch joystick mode.
     pub fn switch_joystick(&self, flag: bool) -> i32 {
-        let p = format!(r#"{{"value":{}}}"#, flag as i32);
-        self.call_api(ROBOT_SPORT_API_ID_SWITCHJOYSTICK, &p)
+        self.set_flag(ROBOT_SPORT_API_ID_SWITCHJOYSTICK, flag)
     }
 
     /// Content mode.
     pub fn content(&self) -> i32 {
-        self.call_api(ROBOT_SPORT_API_ID_CONTENT, "{}")
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_CONTENT, "{}")
     }
 
     /// Heart gesture.
     pub fn heart(&self) -> i32 {
-        self.call_api(ROBOT_SPORT_API_ID_HEART, "{}")
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_HEART, "{}")
     }
 
     /// Pose mode.
     pub fn pose(&self, flag: bool) -> i32 {
-        let p = format!(r#"{{"value":{}}}"#, flag as i32);
-        self.call_api(ROBOT_SPORT_API_ID_POSE, &p)
+        self.set_flag(ROBOT_SPORT_API_ID_POSE, flag)
     }
 
     /// Scrape action.
     pub fn scrape(&self) -> i32 {
-        self.call_api(ROBOT_SPORT_API_ID_SCRAPE, "{}")
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_SCRAPE, "{}")
     }
 
     /// Front flip.
     pub fn front_flip(&self) -> i32 {
-        self.call_api(ROBOT_SPORT_API_ID_FRONTFLIP, "{}")
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_FRONTFLIP, "{}")
     }
 
     /// Front jump.
     pub fn front_jump(&self) -> i32 {
-        self.call_api(ROBOT_SPORT_API_ID_FRONTJUMP, "{}")
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_FRONTJUMP, "{}")
     }
 
     /// Front pounce.
     pub fn front_pounce(&self) -> i32 {
-        self.call_api(ROBOT_SPORT_API_ID_FRONTPOUNCE, "{}")
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_FRONTPOUNCE, "{}")
     }
 
     /// Dance routine 1.
     pub fn dance1(&self) -> i32 {
-        self.call_api(ROBOT_SPORT_API_ID_DANCE1, "{}")
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_DANCE1, "{}")
     }
 
     /// Dance routine 2.
     pub fn dance2(&self) -> i32 {
-        self.call_api(ROBOT_SPORT_API_ID_DANCE2, "{}")
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_DANCE2, "{}")
     }
 
     /// Left flip.
     pub fn left_flip(&self) -> i32 {
-        self.call_api(ROBOT_SPORT_API_ID_LEFTFLIP, "{}")
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_LEFTFLIP, "{}")
     }
 
     /// Back flip.
     pub fn back_flip(&self) -> i32 {
-        self.call_api(ROBOT_SPORT_API_ID_BACKFLIP, "{}")
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_BACKFLIP, "{}")
     }
 
     /// Hand stand.
     pub fn hand_stand(&self, flag: bool) -> i32 {
-        let p = format!(r#"{{"value":{}}}"#, flag as i32);
-        self.call_api(ROBOT_SPORT_API_ID_HANDSTAND, &p)
+        self.set_flag(ROBOT_SPORT_API_ID_HANDSTAND, flag)
     }
 
     /// Free walk mode.
     pub fn free_walk(&self) -> i32 {
-        self.call_api(ROBOT_SPORT_API_ID_FREEWALK, "{}")
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_FREEWALK, "{}")
     }
 
     /// Free bound mode.
     pub fn free_bound(&self, flag: bool) -> i32 {
-        let p = format!(r#"{{"value":{}}}"#, flag as i32);
-        self.call_api(ROBOT_SPORT_API_ID_FREEBOUND, &p)
+        self.set_flag(ROBOT_SPORT_API_ID_FREEBOUND, flag)
     }
 
     /// Free jump mode.
     pub fn free_jump(&self, flag: bool) -> i32 {
-        let p = format!(r#"{{"value":{}}}"#, flag as i32);
-        self.call_api(ROBOT_SPORT_API_ID_FREEJUMP, &p)
+        self.set_flag(ROBOT_SPORT_API_ID_FREEJUMP, flag)
     }
 
     /// Free avoid mode.
     pub fn free_avoid(&self, flag: bool) -> i32 {
-        let p = format!(r#"{{"value":{}}}"#, flag as i32);
-        self.call_api(ROBOT_SPORT_API_ID_FREEAVOID, &p)
+        self.set_flag(ROBOT_SPORT_API_ID_FREEAVOID, flag)
     }
 
     /// Classic walk mode.
     pub fn classic_walk(&self, flag: bool) -> i32 {
-        let p = format!(r#"{{"value":{}}}"#, flag as i32);
-        self.call_api(ROBOT_SPORT_API_ID_CLASSICWALK, &p)
+        self.set_flag(ROBOT_SPORT_API_ID_CLASSICWALK, flag)
     }
 
     /// Walk upright mode.
     pub fn walk_upright(&self, flag: bool) -> i32 {
-        let p = format!(r#"{{"value":{}}}"#, flag as i32);
-        self.call_api(ROBOT_SPORT_API_ID_WALKUPRIGHT, &p)
+        self.set_flag(ROBOT_SPORT_API_ID_WALKUPRIGHT, flag)
     }
 
     /// Cross step mode.
     pub fn cross_step(&self, flag: bool) -> i32 {
-        let p = format!(r#"{{"value":{}}}"#, flag as i32);
-        self.call_api(ROBOT_SPORT_API_ID_CROSSSTEP, &p)
+        self.set_flag(ROBOT_SPORT_API_ID_CROSSSTEP, flag)
     }
 
     /// Set auto-recovery flag.
     pub fn auto_recover_set(&self, flag: bool) -> i32 {
-        let p = format!(r#"{{"value":{}}}"#, flag as i32);
-        self.call_api(ROBOT_SPORT_API_ID_AUTORECOVERY_SET, &p)
+        self.set_flag(ROBOT_SPORT_API_ID_AUTORECOVERY_SET, flag)
     }
 
     /// Get auto-recovery flag. Returns `Ok(bool)` or `Err(api_error_code)`.
+    /// A response without `data` reads as `false`.
     pub fn auto_recover_get(&self) -> Result<bool, i32> {
-        log::info!("SportClient call: api_id={} parameter={{}}", ROBOT_SPORT_API_ID_AUTORECOVERY_GET);
-        // Stub: always returns false
-        Ok(false)
+        let data = self.rpc.call(ROBOT_SPORT_API_ID_AUTORECOVERY_GET, "{}")?;
+        Ok(decode::<JsonData<Option<bool>>>(&data)?.data.unwrap_or(false))
     }
 
     /// Static walk mode.
     pub fn static_walk(&self) -> i32 {
-        self.call_api(ROBOT_SPORT_API_ID_STATICWALK, "{}")
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_STATICWALK, "{}")
     }
 
     /// Trot run mode.
     pub fn trot_run(&self) -> i32 {
-        self.call_api(ROBOT_SPORT_API_ID_TROTRUN, "{}")
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_TROTRUN, "{}")
     }
 
     /// Economic gait mode.
     pub fn economic_gait(&self) -> i32 {
-        self.call_api(ROBOT_SPORT_API_ID_ECONOMICGAIT, "{}")
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_ECONOMICGAIT, "{}")
     }
 
     /// Switch avoid mode.
     pub fn switch_avoid_mode(&self) -> i32 {
-        self.call_api(ROBOT_SPORT_API_ID_SWITCHAVOIDMODE, "{}")
+        self.rpc.call_no_data(ROBOT_SPORT_API_ID_SWITCHAVOIDMODE, "{}")
     }
 }
@@ -0,0 +1,5 @@
+//! Sport service error codes.
+
+pub const ROBOT_SPORT_ERR_CLIENT_POINT_PATH: i32 = 4101;
+pub const ROBOT_SPORT_ERR_SERVER_OVERTIME: i32 = 4201;
+pub const ROBOT_SPORT_ERR_SERVER_NOT_INIT: i32 = 4205;
@@ -0,0 +1,509 @@
+//! Sport commands that wait for the robot to reach the target state.
+//!
+//! `SportClient` calls return as soon as the request is accepted. The
+//! `*_and_wait` variants here additionally watch `rt/sportmodestate` through a
+//! [`SportStateWatcher`] until the expected state is observed, the robot
+//! reports a non-zero `error_code`, or the timeout expires. The `*_async`
+//! variants return a [`SportTransition`] future that resolves the same way;
+//! it needs no particular async runtime.
+//!
+//! Only states received after the command is sent are considered, so a state
+//! left over from before the command can neither complete nor fail it.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Condvar, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::time::{Duration, Instant};
+
+use crate::channel::{ChannelFactory, ChannelSubscriber};
+use crate::error::{Result, SdkError};
+use crate::idl::go2::{SportMode, SportModeState};
+use super::sport_api::TOPIC_SPORT_MODE_STATE;
+use super::SportClient;
+
+/// Body height (m) above which a stand-up is considered complete.
+pub const STAND_UP_BODY_HEIGHT: f32 = 0.30;
+/// Body height (m) below which a stand-down is considered complete.
+pub const STAND_DOWN_BODY_HEIGHT: f32 = 0.10;
+
+#[derive(Default)]
+struct Inner {
+    latest: Option<SportModeState>,
+    /// Number of states received so far.
+    generation: u64,
+    wakers: Vec<Waker>,
+}
+
+#[derive(Default)]
+struct Shared {
+    inner: Mutex<Inner>,
+    cvar: Condvar,
+}
+
+impl Shared {
+    fn push(&self, state: &SportModeState) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.latest = Some(state.clone());
+        inner.generation += 1;
+        inner.wakers.drain(..).for_each(Waker::wake);
+        self.cvar.notify_all();
+    }
+
+    /// Evaluate the latest state if it is newer than `seen`.
+    fn check(
+        inner: &Inner,
+        seen: &mut u64,
+        done: &mut impl FnMut(&SportModeState) -> bool,
+    ) -> Option<Result<SportModeState>> {
+        if inner.generation <= *seen {
+            return None;
+        }
+        *seen = inner.generation;
+        let state = inner.latest.as_ref()?;
+        if state.error_code != 0 {
+            return Some(Err(SdkError::ApiError(state.error_code as i32)));
+        }
+        done(state).then(|| Ok(state.clone()))
+    }
+}
+
+/// Keeps the most recent `SportModeState` and lets callers block on it.
+pub struct SportStateWatcher {
+    subscriber: ChannelSubscriber<SportModeState>,
+    shared: Arc<Shared>,
+}
+
+impl SportStateWatcher {
+    /// Subscribe to `rt/sportmodestate`.
+    pub fn new() -> Result<Self> {
+        let mut subscriber = ChannelFactory::instance()
+            .lock()
+            .unwrap()
+            .create_subscriber(TOPIC_SPORT_MODE_STATE);
+
+        let shared = Arc::new(Shared::default());
+        let handler = Arc::clone(&shared);
+        subscriber.init_channel(move |state: &SportModeState| handler.push(state))?;
+
+        Ok(Self { subscriber, shared })
+    }
+
+    /// The most recently received state, if any.
+    pub fn latest(&self) -> Option<SportModeState> {
+        self.shared.inner.lock().unwrap().latest.clone()
+    }
+
+    /// Number of states received so far. Pass it to [`wait_after`] to only
+    /// consider states that arrive later.
+    ///
+    /// [`wait_after`]: SportStateWatcher::wait_after
+    pub fn generation(&self) -> u64 {
+        self.shared.inner.lock().unwrap().generation
+    }
+
+    /// Block until `done` returns `true` for a state received after this
+    /// call. See [`wait_after`](SportStateWatcher::wait_after).
+    pub fn wait_for(
+        &self,
+        timeout: Duration,
+        done: impl FnMut(&SportModeState) -> bool,
+    ) -> Result<SportModeState> {
+        self.wait_after(self.generation(), timeout, done)
+    }
+
+    /// Block until `done` returns `true` for a state received after
+    /// `generation`.
+    ///
+    /// Returns `SdkError::ApiError` if such a state reports a non-zero
+    /// `error_code` and `SdkError::Timeout` if `timeout` expires first.
+    pub fn wait_after(
+        &self,
+        generation: u64,
+        timeout: Duration,
+        mut done: impl FnMut(&SportModeState) -> bool,
+    ) -> Result<SportModeState> {
+        let deadline = Instant::now() + timeout;
+        let mut seen = generation;
+        let mut inner = self.shared.inner.lock().unwrap();
+
+        loop {
+            if let Some(result) = Shared::check(&inner, &mut seen, &mut done) {
+                return result;
+            }
+
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(SdkError::Timeout);
+            }
+            inner = self.shared.cvar.wait_timeout(inner, deadline - now).unwrap().0;
+        }
+    }
+
+    /// Like [`wait_after`](SportStateWatcher::wait_after), but returns a
+    /// future instead of blocking.
+    pub fn transition_after<F>(&self, generation: u64, timeout: Duration, done: F) -> SportTransition<F>
+    where
+        F: FnMut(&SportModeState) -> bool + Unpin,
+    {
+        SportTransition {
+            shared: Arc::clone(&self.shared),
+            seen: generation,
+            deadline: Instant::now() + timeout,
+            done,
+            failed: None,
+            timer_started: false,
+        }
+    }
+}
+
+impl Drop for SportStateWatcher {
+    fn drop(&mut self) {
+        self.subscriber.close_channel();
+    }
+}
+
+/// A pending sport mode transition, resolved like
+/// [`SportStateWatcher::wait_after`].
+///
+/// Each transition wakes itself at its deadline from a helper thread, so it
+/// can be awaited on any executor.
+pub struct SportTransition<F> {
+    shared: Arc<Shared>,
+    seen: u64,
+    deadline: Instant,
+    done: F,
+    failed: Option<SdkError>,
+    timer_started: bool,
+}
+
+impl<F> SportTransition<F> {
+    fn start_timer(&mut self) {
+        if self.timer_started {
+            return;
+        }
+        self.timer_started = true;
+        let shared = Arc::clone(&self.shared);
+        let deadline = self.deadline;
+        std::thread::spawn(move || {
+            std::thread::sleep(deadline.saturating_duration_since(Instant::now()));
+            let mut inner = shared.inner.lock().unwrap();
+            inner.wakers.drain(..).for_each(Waker::wake);
+        });
+    }
+}
+
+impl<F> Future for SportTransition<F>
+where
+    F: FnMut(&SportModeState) -> bool + Unpin,
+{
+    type Output = Result<SportModeState>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        if let Some(err) = this.failed.take() {
+            return Poll::Ready(Err(err));
+        }
+
+        let shared = Arc::clone(&this.shared);
+        let mut inner = shared.inner.lock().unwrap();
+        if let Some(result) = Shared::check(&inner, &mut this.seen, &mut this.done) {
+            return Poll::Ready(result);
+        }
+        if Instant::now() >= this.deadline {
+            return Poll::Ready(Err(SdkError::Timeout));
+        }
+        if !inner.wakers.iter().any(|w| w.will_wake(cx.waker())) {
+            inner.wakers.push(cx.waker().clone());
+        }
+        drop(inner);
+        this.start_timer();
+        Poll::Pending
+    }
+}
+
+fn is_mode(state: &SportModeState, mode: SportMode) -> bool {
+    state.mode() == Ok(mode)
+}
+
+fn stood_up(s: &SportModeState) -> bool {
+    s.body_height >= STAND_UP_BODY_HEIGHT
+}
+
+fn stood_down(s: &SportModeState) -> bool {
+    s.body_height <= STAND_DOWN_BODY_HEIGHT
+}
+
+fn balancing(s: &SportModeState) -> bool {
+    is_mode(s, SportMode::BalanceStand)
+}
+
+fn recovered(s: &SportModeState) -> bool {
+    is_mode(s, SportMode::BalanceStand) || is_mode(s, SportMode::Idle)
+}
+
+fn damped(s: &SportModeState) -> bool {
+    is_mode(s, SportMode::Damping)
+}
+
+/// The sit action reports a non-zero `progress` while it runs and 0 once
+/// done; a 0 seen before the action has started does not count.
+fn sat_down() -> impl FnMut(&SportModeState) -> bool + Unpin {
+    let mut started = false;
+    move |s| {
+        started |= s.progress != 0.0;
+        started && is_mode(s, SportMode::Sit) && s.progress == 0.0
+    }
+}
+
+impl SportClient {
+    fn call_and_wait(
+        &self,
+        call: impl FnOnce(&Self) -> i32,
+        watcher: &SportStateWatcher,
+        timeout: Duration,
+        done: impl FnMut(&SportModeState) -> bool,
+    ) -> Result<SportModeState> {
+        let generation = watcher.generation();
+        let ret = call(self);
+        if ret != 0 {
+            return Err(SdkError::ApiError(ret));
+        }
+        watcher.wait_after(generation, timeout, done)
+    }
+
+    fn call_async<F>(
+        &self,
+        call: impl FnOnce(&Self) -> i32,
+        watcher: &SportStateWatcher,
+        timeout: Duration,
+        done: F,
+    ) -> SportTransition<F>
+    where
+        F: FnMut(&SportModeState) -> bool + Unpin,
+    {
+        let generation = watcher.generation();
+        let ret = call(self);
+        let mut transition = watcher.transition_after(generation, timeout, done);
+        if ret != 0 {
+            transition.failed = Some(SdkError::ApiError(ret));
+        }
+        transition
+    }
+
+    /// Stand up and wait until the body is raised.
+    pub fn stand_up_and_wait(
+        &self,
+        watcher: &SportStateWatcher,
+        timeout: Duration,
+    ) -> Result<SportModeState> {
+        self.call_and_wait(Self::stand_up, watcher, timeout, stood_up)
+    }
+
+    /// Stand up; the returned future resolves once the body is raised.
+    pub fn stand_up_async(
+        &self,
+        watcher: &SportStateWatcher,
+        timeout: Duration,
+    ) -> SportTransition<impl FnMut(&SportModeState) -> bool + Unpin> {
+        self.call_async(Self::stand_up, watcher, timeout, stood_up)
+    }
+
+    /// Stand down and wait until the body is lowered.
+    pub fn stand_down_and_wait(
+        &self,
+        watcher: &SportStateWatcher,
+        timeout: Duration,
+    ) -> Result<SportModeState> {
+        self.call_and_wait(Self::stand_down, watcher, timeout, stood_down)
+    }
+
+    /// Stand down; the returned future resolves once the body is lowered.
+    pub fn stand_down_async(
+        &self,
+        watcher: &SportStateWatcher,
+        timeout: Duration,
+    ) -> SportTransition<impl FnMut(&SportModeState) -> bool + Unpin> {
+        self.call_async(Self::stand_down, watcher, timeout, stood_down)
+    }
+
+    /// Enter balance stand and wait for the mode switch.
+    pub fn balance_stand_and_wait(
+        &self,
+        watcher: &SportStateWatcher,
+        timeout: Duration,
+    ) -> Result<SportModeState> {
+        self.call_and_wait(Self::balance_stand, watcher, timeout, balancing)
+    }
+
+    /// Enter balance stand; the returned future resolves on the mode switch.
+    pub fn balance_stand_async(
+        &self,
+        watcher: &SportStateWatcher,
+        timeout: Duration,
+    ) -> SportTransition<impl FnMut(&SportModeState) -> bool + Unpin> {
+        self.call_async(Self::balance_stand, watcher, timeout, balancing)
+    }
+
+    /// Recovery stand and wait until the robot is balancing again.
+    pub fn recovery_stand_and_wait(
+        &self,
+        watcher: &SportStateWatcher,
+        timeout: Duration,
+    ) -> Result<SportModeState> {
+        self.call_and_wait(Self::recovery_stand, watcher, timeout, recovered)
+    }
+
+    /// Recovery stand; the returned future resolves once the robot is
+    /// balancing again.
+    pub fn recovery_stand_async(
+        &self,
+        watcher: &SportStateWatcher,
+        timeout: Duration,
+    ) -> SportTransition<impl FnMut(&SportModeState) -> bool + Unpin> {
+        self.call_async(Self::recovery_stand, watcher, timeout, recovered)
+    }
+
+    /// Damp and wait for the damping mode.
+    pub fn damp_and_wait(
+        &self,
+        watcher: &SportStateWatcher,
+        timeout: Duration,
+    ) -> Result<SportModeState> {
+        self.call_and_wait(Self::damp, watcher, timeout, damped)
+    }
+
+    /// Damp; the returned future resolves in the damping mode.
+    pub fn damp_async(
+        &self,
+        watcher: &SportStateWatcher,
+        timeout: Duration,
+    ) -> SportTransition<impl FnMut(&SportModeState) -> bool + Unpin> {
+        self.call_async(Self::damp, watcher, timeout, damped)
+    }
+
+    /// Sit down and wait until the sit action has finished.
+    pub fn sit_and_wait(
+        &self,
+        watcher: &SportStateWatcher,
+        timeout: Duration,
+    ) -> Result<SportModeState> {
+        self.call_and_wait(Self::sit, watcher, timeout, sat_down())
+    }
+
+    /// Sit down; the returned future resolves once the sit action has
+    /// finished.
+    pub fn sit_async(
+        &self,
+        watcher: &SportStateWatcher,
+        timeout: Duration,
+    ) -> SportTransition<impl FnMut(&SportModeState) -> bool + Unpin> {
+        self.call_async(Self::sit, watcher, timeout, sat_down())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::task::Wake;
+
+    fn state(mode: SportMode, progress: f32, body_height: f32) -> SportModeState {
+        SportModeState {
+            mode: mode as u8,
+            progress,
+            body_height,
+            ..Default::default()
+        }
+    }
+
+    fn inner_with(state: SportModeState, generation: u64) -> Inner {
+        Inner {
+            latest: Some(state),
+            generation,
+            wakers: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn ignores_states_from_before_the_command() {
+        let inner = inner_with(state(SportMode::BalanceStand, 0.0, 0.32), 5);
+        let mut seen = 5;
+        assert!(Shared::check(&inner, &mut seen, &mut stood_up).is_none());
+
+        let mut stale_error = inner_with(state(SportMode::Damping, 0.0, 0.0), 5);
+        stale_error.latest.as_mut().unwrap().error_code = 7;
+        assert!(Shared::check(&stale_error, &mut seen, &mut stood_up).is_none());
+    }
+
+    #[test]
+    fn completes_and_fails_on_new_states() {
+        let mut seen = 5;
+        let tall = inner_with(state(SportMode::BalanceStand, 0.0, 0.32), 6);
+        assert!(matches!(Shared::check(&tall, &mut seen, &mut stood_up), Some(Ok(_))));
+
+        let mut failed = inner_with(state(SportMode::Damping, 0.0, 0.0), 7);
+        failed.latest.as_mut().unwrap().error_code = 7;
+        assert!(matches!(
+            Shared::check(&failed, &mut seen, &mut stood_up),
+            Some(Err(SdkError::ApiError(7)))
+        ));
+    }
+
+    #[test]
+    fn sit_waits_for_progress_to_return_to_zero() {
+        let mut done = sat_down();
+        assert!(!done(&state(SportMode::Sit, 0.0, 0.2)));
+        assert!(!done(&state(SportMode::Sit, 0.4, 0.2)));
+        assert!(done(&state(SportMode::Sit, 0.0, 0.1)));
+    }
+
+    struct Flag(Mutex<bool>);
+
+    impl Wake for Flag {
+        fn wake(self: Arc<Self>) {
+            *self.0.lock().unwrap() = true;
+        }
+    }
+
+    #[test]
+    fn transition_resolves_on_a_new_state() {
+        let shared = Arc::new(Shared::default());
+        shared.push(&state(SportMode::BalanceStand, 0.0, 0.32));
+        let mut transition = SportTransition {
+            shared: Arc::clone(&shared),
+            seen: 1,
+            deadline: Instant::now() + Duration::from_secs(10),
+            done: stood_up,
+            failed: None,
+            timer_started: false,
+        };
+
+        let flag = Arc::new(Flag(Mutex::new(false)));
+        let waker = Waker::from(Arc::clone(&flag));
+        let mut cx = Context::from_waker(&waker);
+        assert!(Pin::new(&mut transition).poll(&mut cx).is_pending());
+
+        shared.push(&state(SportMode::BalanceStand, 0.0, 0.33));
+        assert!(*flag.0.lock().unwrap());
+        assert!(matches!(Pin::new(&mut transition).poll(&mut cx), Poll::Ready(Ok(_))));
+    }
+
+    #[test]
+    fn transition_times_out() {
+        let shared = Arc::new(Shared::default());
+        let mut transition = SportTransition {
+            shared,
+            seen: 0,
+            deadline: Instant::now(),
+            done: stood_up,
+            failed: None,
+            timer_started: false,
+        };
+        let waker = Waker::from(Arc::new(Flag(Mutex::new(false))));
+        let mut cx = Context::from_waker(&waker);
+        assert!(matches!(
+            Pin::new(&mut transition).poll(&mut cx),
+            Poll::Ready(Err(SdkError::Timeout))
+        ));
+    }
+}
@@ -0,0 +1,154 @@
+//! Path generation and validation for `SportClient::trajectory_follow`.
+
+use crate::idl::go2::PathPoint;
+use super::sport_error::ROBOT_SPORT_ERR_CLIENT_POINT_PATH;
+
+/// Number of points sent per trajectory-follow request.
+pub const PATH_POINT_COUNT: usize = 30;
+/// Time spacing between consecutive path points, in seconds.
+pub const PATH_POINT_INTERVAL: f32 = 0.06;
+
+/// Maximum forward speed accepted in a path point (m/s).
+pub const PATH_MAX_VX: f32 = 2.5;
+/// Maximum lateral speed accepted in a path point (m/s).
+pub const PATH_MAX_VY: f32 = 1.0;
+/// Maximum yaw rate accepted in a path point (rad/s).
+pub const PATH_MAX_VYAW: f32 = 4.0;
+
+/// Sample `PATH_POINT_COUNT` points from a parametric curve.
+///
+/// `curve` is evaluated at `start + i * PATH_POINT_INTERVAL` and returns the
+/// pose and velocity for that parameter; `t_from_start` is filled in here.
+pub fn sample_path(start: f32, curve: impl Fn(f32) -> PathPoint) -> Vec<PathPoint> {
+    (0..PATH_POINT_COUNT)
+        .map(|i| {
+            let offset = i as f32 * PATH_POINT_INTERVAL;
+            PathPoint {
+                t_from_start: offset,
+                ..curve(start + offset)
+            }
+        })
+        .collect()
+}
+
+/// Check a path before sending it.
+///
+/// Returns `Err(ROBOT_SPORT_ERR_CLIENT_POINT_PATH)` if the path is empty,
+/// contains non-finite values, has non-increasing `t_from_start`, or exceeds
+/// the velocity limits.
+pub fn validate_path(path: &[PathPoint]) -> Result<(), i32> {
+    if path.is_empty() {
+        log::warn!("Path rejected: no points");
+        return Err(ROBOT_SPORT_ERR_CLIENT_POINT_PATH);
+    }
+
+    let mut last_t = f32::NEG_INFINITY;
+    for (i, p) in path.iter().enumerate() {
+        let values = [p.t_from_start, p.x, p.y, p.yaw, p.vx, p.vy, p.vyaw];
+        if values.iter().any(|v| !v.is_finite()) {
+            log::warn!("Path rejected: point {} has a non-finite value", i);
+            return Err(ROBOT_SPORT_ERR_CLIENT_POINT_PATH);
+        }
+        if p.t_from_start <= last_t {
+            log::warn!(
+                "Path rejected: point {} t_from_start={} is not after {}",
+                i,
+                p.t_from_start,
+                last_t
+            );
+            return Err(ROBOT_SPORT_ERR_CLIENT_POINT_PATH);
+        }
+        if p.vx.abs() > PATH_MAX_VX || p.vy.abs() > PATH_MAX_VY || p.vyaw.abs() > PATH_MAX_VYAW {
+            log::warn!(
+                "Path rejected: point {} velocity ({}, {}, {}) out of bounds",
+                i,
+                p.vx,
+                p.vy,
+                p.vyaw
+            );
+            return Err(ROBOT_SPORT_ERR_CLIENT_POINT_PATH);
+        }
+        last_t = p.t_from_start;
+    }
+
+    Ok(())
+}
+
+/// Validate a path and encode it as the `trajectory_follow` parameter.
+///
+/// Shared by the Go2 and B2 sport clients. Returns
+/// `Err(ROBOT_SPORT_ERR_CLIENT_POINT_PATH)` for a path rejected by
+/// [`validate_path`] or one that fails to serialize.
+pub fn encode_path(path: &[PathPoint]) -> Result<String, i32> {
+    validate_path(path)?;
+    serde_json::to_string(path).map_err(|e| {
+        log::error!("trajectory_follow: failed to encode path: {}", e);
+        ROBOT_SPORT_ERR_CLIENT_POINT_PATH
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(t: f32, vx: f32, vy: f32, vyaw: f32) -> PathPoint {
+        PathPoint {
+            t_from_start: t,
+            vx,
+            vy,
+            vyaw,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn sampled_path_is_valid() {
+        let path = sample_path(0.0, |t| point(0.0, 0.5, 0.0, 0.2 * t));
+        assert_eq!(path.len(), PATH_POINT_COUNT);
+        assert_eq!(validate_path(&path), Ok(()));
+    }
+
+    #[test]
+    fn rejects_empty_path() {
+        assert_eq!(validate_path(&[]), Err(ROBOT_SPORT_ERR_CLIENT_POINT_PATH));
+    }
+
+    #[test]
+    fn rejects_non_monotonic_time() {
+        let repeated = [point(0.0, 0.0, 0.0, 0.0), point(0.0, 0.0, 0.0, 0.0)];
+        assert_eq!(validate_path(&repeated), Err(ROBOT_SPORT_ERR_CLIENT_POINT_PATH));
+
+        let backwards = [point(0.1, 0.0, 0.0, 0.0), point(0.05, 0.0, 0.0, 0.0)];
+        assert_eq!(validate_path(&backwards), Err(ROBOT_SPORT_ERR_CLIENT_POINT_PATH));
+    }
+
+    #[test]
+    fn rejects_out_of_bound_velocities() {
+        for p in [
+            point(0.0, PATH_MAX_VX + 0.1, 0.0, 0.0),
+            point(0.0, -PATH_MAX_VX - 0.1, 0.0, 0.0),
+            point(0.0, 0.0, PATH_MAX_VY + 0.1, 0.0),
+            point(0.0, 0.0, 0.0, -PATH_MAX_VYAW - 0.1),
+        ] {
+            assert_eq!(validate_path(&[p]), Err(ROBOT_SPORT_ERR_CLIENT_POINT_PATH));
+        }
+        let at_limit = point(0.0, PATH_MAX_VX, -PATH_MAX_VY, PATH_MAX_VYAW);
+        assert_eq!(validate_path(&[at_limit]), Ok(()));
+    }
+
+    #[test]
+    fn rejects_non_finite_values() {
+        let p = PathPoint {
+            x: f32::NAN,
+            ..Default::default()
+        };
+        assert_eq!(validate_path(&[p]), Err(ROBOT_SPORT_ERR_CLIENT_POINT_PATH));
+    }
+
+    #[test]
+    fn encode_path_validates_first() {
+        assert_eq!(encode_path(&[]), Err(ROBOT_SPORT_ERR_CLIENT_POINT_PATH));
+        let json = encode_path(&[point(0.0, 0.5, 0.0, 0.0)]).unwrap();
+        assert!(json.starts_with(r#"[{"t_from_start":0.0"#));
+    }
+}
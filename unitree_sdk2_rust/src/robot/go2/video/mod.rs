@@ -0,0 +1,6 @@
+//! Video client for Go2.
+pub mod video_api;
+pub mod video_client;
+pub mod video_error;
+
+pub use video_client::VideoClient;
@@ -0,0 +1,3 @@
+//! Video service error codes.
+
+pub const ROBOT_VIDEO_ERR_COMM: i32 = 100;
@@ -0,0 +1,31 @@
+//! H1 locomotion service name, version, method ID and FSM ID constants.
+
+pub const LOCO_SERVICE_NAME: &str = "loco";
+pub const LOCO_API_VERSION: &str = "2.0.0.0";
+
+pub const ROBOT_API_ID_LOCO_GET_FSM_ID: i32 = 8001;
+pub const ROBOT_API_ID_LOCO_GET_FSM_MODE: i32 = 8002;
+pub const ROBOT_API_ID_LOCO_GET_BALANCE_MODE: i32 = 8003;
+pub const ROBOT_API_ID_LOCO_GET_SWING_HEIGHT: i32 = 8004;
+pub const ROBOT_API_ID_LOCO_GET_STAND_HEIGHT: i32 = 8005;
+/// Deprecated by the robot firmware.
+pub const ROBOT_API_ID_LOCO_GET_PHASE: i32 = 8006;
+pub const ROBOT_API_ID_LOCO_SET_FSM_ID: i32 = 8101;
+pub const ROBOT_API_ID_LOCO_SET_BALANCE_MODE: i32 = 8102;
+pub const ROBOT_API_ID_LOCO_SET_SWING_HEIGHT: i32 = 8103;
+pub const ROBOT_API_ID_LOCO_SET_STAND_HEIGHT: i32 = 8104;
+pub const ROBOT_API_ID_LOCO_SET_VELOCITY: i32 = 8105;
+pub const ROBOT_API_ID_LOCO_SET_PHASE: i32 = 8106;
+pub const ROBOT_API_ID_LOCO_SET_ARM_TASK: i32 = 8107;
+pub const ROBOT_API_ID_LOCO_ENABLE_ODOM: i32 = 8201;
+pub const ROBOT_API_ID_LOCO_DISABLE_ODOM: i32 = 8202;
+pub const ROBOT_API_ID_LOCO_GET_ODOM: i32 = 8203;
+pub const ROBOT_API_ID_LOCO_SET_TARGET_POSITION: i32 = 8204;
+
+pub const LOCO_FSM_ID_ZERO_TORQUE: i32 = 0;
+pub const LOCO_FSM_ID_DAMP: i32 = 1;
+pub const LOCO_FSM_ID_STAND_UP: i32 = 2;
+pub const LOCO_FSM_ID_START: i32 = 204;
+
+/// Velocity command duration used for continuous moves (10 days), in seconds.
+pub const LOCO_CONTINUOUS_MOVE_DURATION: f32 = 864000.0;
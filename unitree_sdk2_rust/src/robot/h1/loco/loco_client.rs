@@ -0,0 +1,256 @@
+//! H1LocoClient - high-level locomotion API for the H1 humanoid.
+//!
+//! Shares its request plumbing with the G1 `LocoClient`; see
+//! `robot::loco_common`.
+
+use serde::{Deserialize, Serialize};
+
+use crate::robot::loco_common::{LocoApi, LocoCore};
+use crate::rpc::decode;
+use super::loco_api::*;
+
+static H1_LOCO_API: LocoApi = LocoApi {
+    service_name: LOCO_SERVICE_NAME,
+    version: LOCO_API_VERSION,
+    get_fsm_id: ROBOT_API_ID_LOCO_GET_FSM_ID,
+    get_fsm_mode: ROBOT_API_ID_LOCO_GET_FSM_MODE,
+    get_balance_mode: ROBOT_API_ID_LOCO_GET_BALANCE_MODE,
+    get_swing_height: ROBOT_API_ID_LOCO_GET_SWING_HEIGHT,
+    get_stand_height: ROBOT_API_ID_LOCO_GET_STAND_HEIGHT,
+    get_phase: ROBOT_API_ID_LOCO_GET_PHASE,
+    set_fsm_id: ROBOT_API_ID_LOCO_SET_FSM_ID,
+    set_balance_mode: ROBOT_API_ID_LOCO_SET_BALANCE_MODE,
+    set_swing_height: ROBOT_API_ID_LOCO_SET_SWING_HEIGHT,
+    set_stand_height: ROBOT_API_ID_LOCO_SET_STAND_HEIGHT,
+    set_velocity: ROBOT_API_ID_LOCO_SET_VELOCITY,
+    set_arm_task: ROBOT_API_ID_LOCO_SET_ARM_TASK,
+    fsm_zero_torque: LOCO_FSM_ID_ZERO_TORQUE,
+    fsm_damp: LOCO_FSM_ID_DAMP,
+    fsm_stand_up: LOCO_FSM_ID_STAND_UP,
+    fsm_start: LOCO_FSM_ID_START,
+    shake_hand_tasks: [1, 2],
+    continuous_move_duration: LOCO_CONTINUOUS_MOVE_DURATION,
+};
+
+#[derive(Debug, Serialize)]
+struct TargetPosition {
+    x: f32,
+    y: f32,
+    yaw: f32,
+    relative: bool,
+}
+
+/// Planar odometry reported by the locomotion service.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct Odometry {
+    /// Position along x, in meters.
+    pub x: f32,
+    /// Position along y, in meters.
+    pub y: f32,
+    /// Heading, in radians.
+    #[serde(rename = "z")]
+    pub yaw: f32,
+}
+
+/// Controls the H1 locomotion FSM, posture, walking and odometry.
+pub struct H1LocoClient {
+    core: LocoCore,
+}
+
+impl H1LocoClient {
+    /// Create a new `H1LocoClient`.
+    pub fn new() -> Self {
+        Self {
+            core: LocoCore::new(&H1_LOCO_API),
+        }
+    }
+
+    /// Set the RPC timeout in seconds.
+    pub fn set_timeout(&mut self, timeout: f32) {
+        self.core.set_timeout(timeout);
+    }
+
+    /// Initialize the client.
+    pub fn init(&mut self) {
+        self.core.init(
+            "H1LocoClient",
+            &[
+                ROBOT_API_ID_LOCO_SET_PHASE,
+                ROBOT_API_ID_LOCO_ENABLE_ODOM,
+                ROBOT_API_ID_LOCO_DISABLE_ODOM,
+                ROBOT_API_ID_LOCO_GET_ODOM,
+                ROBOT_API_ID_LOCO_SET_TARGET_POSITION,
+            ],
+        );
+    }
+
+    /// Get the current FSM id. Returns `Ok(fsm_id)` or `Err(api_error_code)`.
+    pub fn get_fsm_id(&self) -> Result<i32, i32> {
+        self.core.get_fsm_id()
+    }
+
+    /// Get the current FSM mode. Returns `Ok(fsm_mode)` or `Err(api_error_code)`.
+    pub fn get_fsm_mode(&self) -> Result<i32, i32> {
+        self.core.get_fsm_mode()
+    }
+
+    /// Get the balance mode. Returns `Ok(balance_mode)` or `Err(api_error_code)`.
+    pub fn get_balance_mode(&self) -> Result<i32, i32> {
+        self.core.get_balance_mode()
+    }
+
+    /// Get the swing height. Returns `Ok(height)` or `Err(api_error_code)`.
+    pub fn get_swing_height(&self) -> Result<f32, i32> {
+        self.core.get_swing_height()
+    }
+
+    /// Get the stand height. Returns `Ok(height)` or `Err(api_error_code)`.
+    pub fn get_stand_height(&self) -> Result<f32, i32> {
+        self.core.get_stand_height()
+    }
+
+    /// Get the gait phase. Deprecated by the robot firmware.
+    pub fn get_phase(&self) -> Result<Vec<f32>, i32> {
+        self.core.get_phase()
+    }
+
+    /// Start odometry integration.
+    pub fn enable_odom(&self) -> i32 {
+        self.core.rpc.call_no_data(ROBOT_API_ID_LOCO_ENABLE_ODOM, "{}")
+    }
+
+    /// Stop odometry integration.
+    pub fn disable_odom(&self) -> i32 {
+        self.core.rpc.call_no_data(ROBOT_API_ID_LOCO_DISABLE_ODOM, "{}")
+    }
+
+    /// Get the current odometry. Returns `Ok(Odometry)` or `Err(api_error_code)`.
+    pub fn get_odom(&self) -> Result<Odometry, i32> {
+        let data = self.core.rpc.call(ROBOT_API_ID_LOCO_GET_ODOM, "{}")?;
+        decode(&data)
+    }
+
+    /// Switch the locomotion FSM to `fsm_id` (see `LOCO_FSM_ID_*`).
+    pub fn set_fsm_id(&self, fsm_id: i32) -> i32 {
+        self.core.set_fsm_id(fsm_id)
+    }
+
+    /// Set balance mode (0: balance stand, 1: continuous gait).
+    pub fn set_balance_mode(&self, balance_mode: i32) -> i32 {
+        self.core.set_balance_mode(balance_mode)
+    }
+
+    /// Set foot swing height.
+    pub fn set_swing_height(&self, swing_height: f32) -> i32 {
+        self.core.set_swing_height(swing_height)
+    }
+
+    /// Set stand height.
+    pub fn set_stand_height(&self, stand_height: f32) -> i32 {
+        self.core.set_stand_height(stand_height)
+    }
+
+    /// Walk with velocity (m/s for vx/vy, rad/s for omega) for `duration` seconds.
+    pub fn set_velocity(&self, vx: f32, vy: f32, omega: f32, duration: f32) -> i32 {
+        self.core.set_velocity(vx, vy, omega, duration)
+    }
+
+    /// Set the gait phase.
+    pub fn set_phase(&self, phase: &[f32]) -> i32 {
+        self.core.set(ROBOT_API_ID_LOCO_SET_PHASE, phase)
+    }
+
+    /// Walk to a target pose, relative to the current pose or in the
+    /// odometry frame.
+    pub fn set_target_position(&self, x: f32, y: f32, yaw: f32, relative: bool) -> i32 {
+        let p = serde_json::to_string(&TargetPosition { x, y, yaw, relative }).unwrap_or_default();
+        self.core.rpc.call_no_data(ROBOT_API_ID_LOCO_SET_TARGET_POSITION, &p)
+    }
+
+    /// Run an arm task by id.
+    pub fn set_task_id(&self, task_id: i32) -> i32 {
+        self.core.set_task_id(task_id)
+    }
+
+    /// Damp all motors.
+    pub fn damp(&self) -> i32 {
+        self.core.damp()
+    }
+
+    /// Start the locomotion controller.
+    pub fn start(&self) -> i32 {
+        self.core.start()
+    }
+
+    /// Stand up.
+    pub fn stand_up(&self) -> i32 {
+        self.core.stand_up()
+    }
+
+    /// Zero torque on all motors.
+    pub fn zero_torque(&self) -> i32 {
+        self.core.zero_torque()
+    }
+
+    /// Stop all movement.
+    pub fn stop_move(&self) -> i32 {
+        self.core.stop_move()
+    }
+
+    /// Stand at the maximum height.
+    pub fn high_stand(&self) -> i32 {
+        self.core.high_stand()
+    }
+
+    /// Stand at the minimum height.
+    pub fn low_stand(&self) -> i32 {
+        self.core.low_stand()
+    }
+
+    /// Move with velocity, either for one second or continuously until changed.
+    pub fn move_with(&self, vx: f32, vy: f32, vyaw: f32, continuous: bool) -> i32 {
+        self.core.move_with(vx, vy, vyaw, continuous)
+    }
+
+    /// Move with velocity, honouring the mode set by `switch_move_mode`.
+    pub fn move_cmd(&self, vx: f32, vy: f32, vyaw: f32) -> i32 {
+        self.core.move_cmd(vx, vy, vyaw)
+    }
+
+    /// Enter balance stand.
+    pub fn balance_stand(&self) -> i32 {
+        self.core.balance_stand()
+    }
+
+    /// Keep stepping in place while standing.
+    pub fn continuous_gait(&self, flag: bool) -> i32 {
+        self.core.continuous_gait(flag)
+    }
+
+    /// Make `move_cmd` continuous (`true`) or one-second (`false`). Local only.
+    pub fn switch_move_mode(&mut self, flag: bool) -> i32 {
+        self.core.switch_move_mode(flag)
+    }
+
+    /// Choose which foot steps next (`true`: right, `false`: left).
+    pub fn set_next_foot(&self, foot: bool) -> i32 {
+        self.set_phase(if foot { &[0.0, 1.0] } else { &[1.0, 0.0] })
+    }
+
+    /// Wave hand.
+    pub fn wave_hand(&self) -> i32 {
+        self.set_task_id(0)
+    }
+
+    /// Shake hand. `Some(0)` extends the hand, `Some(1)` withdraws it, and
+    /// `None` alternates between the two.
+    pub fn shake_hand(&mut self, stage: Option<u8>) -> i32 {
+        self.core.shake_hand(stage)
+    }
+}
+
+impl Default for H1LocoClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,6 @@
+//! H1 locomotion service error codes.
+
+pub const ROBOT_LOCO_ERR_LOCOSTATE_NOT_AVAILABLE: i32 = 8301;
+pub const ROBOT_LOCO_ERR_INVALID_FSM_ID: i32 = 8302;
+pub const ROBOT_LOCO_ERR_ODOMSTATE_NOT_AVAILABLE: i32 = 8303;
+pub const ROBOT_LOCO_ERR_INVALID_TASK_ID: i32 = 8304;
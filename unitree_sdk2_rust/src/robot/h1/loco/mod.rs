@@ -0,0 +1,6 @@
+//! Locomotion client for H1.
+pub mod loco_api;
+pub mod loco_client;
+pub mod loco_error;
+
+pub use loco_client::{H1LocoClient, Odometry};
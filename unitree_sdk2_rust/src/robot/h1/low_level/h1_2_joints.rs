@@ -0,0 +1,162 @@
+//! H1-2 motor layout on the `unitree_hg` IDL.
+//!
+//! H1-2 has 27 motors. Gains depend on the gearbox size of each motor, as
+//! in `h1/low_level/h1_27dof_example.cpp`. The ankle slots carry pitch and
+//! roll in PR mode and the A/B motors in AB mode; see
+//! [`AnkleMode`](crate::control::AnkleMode).
+
+use crate::idl::hg::LowCmd;
+use crate::robot::info::joint_table::{JointInfo, H1_2_JOINTS};
+use super::h1_joints::MotorGains;
+
+/// Number of H1-2 motors.
+pub const H1_2_NUM_MOTOR: usize = 27;
+
+/// Gearbox size of a motor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MotorType {
+    GearboxS,
+    GearboxM,
+    GearboxL,
+}
+
+impl MotorType {
+    /// Default PD gains for this gearbox.
+    pub fn gains(self) -> MotorGains {
+        match self {
+            MotorType::GearboxS => MotorGains::new(80.0, 2.0),
+            MotorType::GearboxM => MotorGains::new(100.0, 3.0),
+            MotorType::GearboxL => MotorGains::new(200.0, 5.0),
+        }
+    }
+}
+
+/// H1-2 joints, in motor order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(usize)]
+pub enum H1_2Joint {
+    LeftHipYaw = 0,
+    LeftHipPitch = 1,
+    LeftHipRoll = 2,
+    LeftKnee = 3,
+    LeftAnklePitch = 4,
+    LeftAnkleRoll = 5,
+    RightHipYaw = 6,
+    RightHipPitch = 7,
+    RightHipRoll = 8,
+    RightKnee = 9,
+    RightAnklePitch = 10,
+    RightAnkleRoll = 11,
+    WaistYaw = 12,
+    LeftShoulderPitch = 13,
+    LeftShoulderRoll = 14,
+    LeftShoulderYaw = 15,
+    LeftElbow = 16,
+    LeftWristRoll = 17,
+    LeftWristPitch = 18,
+    LeftWristYaw = 19,
+    RightShoulderPitch = 20,
+    RightShoulderRoll = 21,
+    RightShoulderYaw = 22,
+    RightElbow = 23,
+    RightWristRoll = 24,
+    RightWristPitch = 25,
+    RightWristYaw = 26,
+}
+
+impl H1_2Joint {
+    pub const ALL: [H1_2Joint; H1_2_NUM_MOTOR] = [
+        H1_2Joint::LeftHipYaw,
+        H1_2Joint::LeftHipPitch,
+        H1_2Joint::LeftHipRoll,
+        H1_2Joint::LeftKnee,
+        H1_2Joint::LeftAnklePitch,
+        H1_2Joint::LeftAnkleRoll,
+        H1_2Joint::RightHipYaw,
+        H1_2Joint::RightHipPitch,
+        H1_2Joint::RightHipRoll,
+        H1_2Joint::RightKnee,
+        H1_2Joint::RightAnklePitch,
+        H1_2Joint::RightAnkleRoll,
+        H1_2Joint::WaistYaw,
+        H1_2Joint::LeftShoulderPitch,
+        H1_2Joint::LeftShoulderRoll,
+        H1_2Joint::LeftShoulderYaw,
+        H1_2Joint::LeftElbow,
+        H1_2Joint::LeftWristRoll,
+        H1_2Joint::LeftWristPitch,
+        H1_2Joint::LeftWristYaw,
+        H1_2Joint::RightShoulderPitch,
+        H1_2Joint::RightShoulderRoll,
+        H1_2Joint::RightShoulderYaw,
+        H1_2Joint::RightElbow,
+        H1_2Joint::RightWristRoll,
+        H1_2Joint::RightWristPitch,
+        H1_2Joint::RightWristYaw,
+    ];
+
+    /// Motor index in `LowCmd`/`LowState`.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Name and limits from the joint table.
+    pub fn info(self) -> &'static JointInfo {
+        &H1_2_JOINTS[self.index()]
+    }
+
+    /// Gearbox size of the motor.
+    pub fn motor_type(self) -> MotorType {
+        match self {
+            H1_2Joint::LeftKnee | H1_2Joint::RightKnee => MotorType::GearboxL,
+            H1_2Joint::LeftHipYaw
+            | H1_2Joint::LeftHipPitch
+            | H1_2Joint::LeftHipRoll
+            | H1_2Joint::RightHipYaw
+            | H1_2Joint::RightHipPitch
+            | H1_2Joint::RightHipRoll
+            | H1_2Joint::WaistYaw => MotorType::GearboxM,
+            _ => MotorType::GearboxS,
+        }
+    }
+
+    /// Default PD gains.
+    pub fn gains(self) -> MotorGains {
+        self.motor_type().gains()
+    }
+}
+
+impl TryFrom<usize> for H1_2Joint {
+    type Error = usize;
+
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        H1_2Joint::ALL.get(value).copied().ok_or(value)
+    }
+}
+
+/// Default gains per motor.
+pub fn h1_2_default_gains() -> [MotorGains; H1_2_NUM_MOTOR] {
+    H1_2Joint::ALL.map(H1_2Joint::gains)
+}
+
+/// Standing pose: the zero posture, with straight legs and arms down.
+pub const H1_2_STAND_POSE: [f32; H1_2_NUM_MOTOR] = [0.0; H1_2_NUM_MOTOR];
+
+/// Command every motor towards the standing pose: `ratio` 0 holds `start`,
+/// 1 is the full pose. Uses the default gains. `mode_pr` and
+/// `mode_machine` are left to the caller, e.g.
+/// [`HgLowCmdPublisher`](crate::control::HgLowCmdPublisher).
+pub fn set_h1_2_stand(cmd: &mut LowCmd, start: &[f32; H1_2_NUM_MOTOR], ratio: f32) {
+    let ratio = ratio.clamp(0.0, 1.0);
+    for joint in H1_2Joint::ALL {
+        let i = joint.index();
+        let gains = joint.gains();
+        let m = &mut cmd.motor_cmd[i];
+        m.mode = 1;
+        m.q = start[i] + (H1_2_STAND_POSE[i] - start[i]) * ratio;
+        m.dq = 0.0;
+        m.tau = 0.0;
+        m.kp = gains.kp;
+        m.kd = gains.kd;
+    }
+}
@@ -0,0 +1,168 @@
+//! H1 motor layout on the `unitree_go` IDL.
+//!
+//! H1 uses 20 `LowCmd` slots; slot 9 is unused. Ankles and arms are
+//! "weak" motors driven in servo mode (`0x01`) with low gains, the rest are
+//! "strong" motors driven in FOC mode (`0x0A`) with high gains, as in
+//! `h1/low_level/humanoid.hpp`.
+
+use crate::idl::go2::LowCmd;
+use crate::robot::info::joint_table::{JointInfo, H1_JOINTS};
+
+/// Number of `LowCmd` slots used by H1, including the unused slot 9.
+pub const H1_NUM_MOTOR: usize = 20;
+/// Slot with no motor behind it.
+pub const H1_UNUSED_SLOT: usize = 9;
+
+/// PD gains of one motor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MotorGains {
+    pub kp: f32,
+    pub kd: f32,
+}
+
+impl MotorGains {
+    pub const fn new(kp: f32, kd: f32) -> Self {
+        Self { kp, kd }
+    }
+}
+
+/// Gains for the ankle and arm motors.
+pub const H1_WEAK_GAINS: MotorGains = MotorGains::new(60.0, 1.5);
+/// Gains for the hip, knee and torso motors.
+pub const H1_STRONG_GAINS: MotorGains = MotorGains::new(200.0, 5.0);
+
+/// Motor mode for weak motors (servo).
+pub const H1_WEAK_MOTOR_MODE: u8 = 0x01;
+/// Motor mode for strong motors (FOC).
+pub const H1_STRONG_MOTOR_MODE: u8 = 0x0A;
+
+/// H1 joints, with their `LowCmd` slots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(usize)]
+pub enum H1Joint {
+    RightHipRoll = 0,
+    RightHipPitch = 1,
+    RightKnee = 2,
+    LeftHipRoll = 3,
+    LeftHipPitch = 4,
+    LeftKnee = 5,
+    WaistYaw = 6,
+    LeftHipYaw = 7,
+    RightHipYaw = 8,
+    LeftAnkle = 10,
+    RightAnkle = 11,
+    RightShoulderPitch = 12,
+    RightShoulderRoll = 13,
+    RightShoulderYaw = 14,
+    RightElbow = 15,
+    LeftShoulderPitch = 16,
+    LeftShoulderRoll = 17,
+    LeftShoulderYaw = 18,
+    LeftElbow = 19,
+}
+
+impl H1Joint {
+    /// Every joint, in slot order.
+    pub const ALL: [H1Joint; 19] = [
+        H1Joint::RightHipRoll,
+        H1Joint::RightHipPitch,
+        H1Joint::RightKnee,
+        H1Joint::LeftHipRoll,
+        H1Joint::LeftHipPitch,
+        H1Joint::LeftKnee,
+        H1Joint::WaistYaw,
+        H1Joint::LeftHipYaw,
+        H1Joint::RightHipYaw,
+        H1Joint::LeftAnkle,
+        H1Joint::RightAnkle,
+        H1Joint::RightShoulderPitch,
+        H1Joint::RightShoulderRoll,
+        H1Joint::RightShoulderYaw,
+        H1Joint::RightElbow,
+        H1Joint::LeftShoulderPitch,
+        H1Joint::LeftShoulderRoll,
+        H1Joint::LeftShoulderYaw,
+        H1Joint::LeftElbow,
+    ];
+
+    /// Motor index in `LowCmd`/`LowState`.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Name and limits from the joint table.
+    pub fn info(self) -> &'static JointInfo {
+        let i = H1Joint::ALL.iter().position(|&j| j == self).unwrap();
+        &H1_JOINTS[i]
+    }
+
+    /// Whether this is a weak (ankle or arm) motor.
+    pub fn is_weak(self) -> bool {
+        matches!(self, H1Joint::LeftAnkle | H1Joint::RightAnkle) || self.index() >= 12
+    }
+
+    /// Default PD gains.
+    pub fn gains(self) -> MotorGains {
+        if self.is_weak() { H1_WEAK_GAINS } else { H1_STRONG_GAINS }
+    }
+
+    /// Motor mode to command.
+    pub fn motor_mode(self) -> u8 {
+        if self.is_weak() { H1_WEAK_MOTOR_MODE } else { H1_STRONG_MOTOR_MODE }
+    }
+
+    /// Position of this joint in the standing pose.
+    pub fn stand_position(self) -> f32 {
+        match self {
+            H1Joint::LeftHipPitch | H1Joint::RightHipPitch => -0.5,
+            H1Joint::LeftKnee | H1Joint::RightKnee => 1.0,
+            H1Joint::LeftAnkle | H1Joint::RightAnkle => -0.5,
+            H1Joint::LeftShoulderPitch | H1Joint::RightShoulderPitch => 0.4,
+            _ => 0.0,
+        }
+    }
+}
+
+impl TryFrom<usize> for H1Joint {
+    type Error = usize;
+
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        H1Joint::ALL.iter().copied().find(|j| j.index() == value).ok_or(value)
+    }
+}
+
+/// Default gains per slot; the unused slot gets zero gains.
+pub fn h1_default_gains() -> [MotorGains; H1_NUM_MOTOR] {
+    let mut gains = [MotorGains::new(0.0, 0.0); H1_NUM_MOTOR];
+    for joint in H1Joint::ALL {
+        gains[joint.index()] = joint.gains();
+    }
+    gains
+}
+
+/// Standing pose per slot: slightly bent legs, arms forward.
+pub fn h1_stand_pose() -> [f32; H1_NUM_MOTOR] {
+    let mut pose = [0.0; H1_NUM_MOTOR];
+    for joint in H1Joint::ALL {
+        pose[joint.index()] = joint.stand_position();
+    }
+    pose
+}
+
+/// Command every joint towards the standing pose: `ratio` 0 holds `start`,
+/// 1 is the full pose. Uses the default gains and motor modes and leaves
+/// the unused slot alone.
+pub fn set_h1_stand(cmd: &mut LowCmd, start: &[f32; H1_NUM_MOTOR], ratio: f32) {
+    let ratio = ratio.clamp(0.0, 1.0);
+    for joint in H1Joint::ALL {
+        let i = joint.index();
+        let gains = joint.gains();
+        let m = &mut cmd.motor_cmd[i];
+        m.mode = joint.motor_mode();
+        m.q = start[i] + (joint.stand_position() - start[i]) * ratio;
+        m.dq = 0.0;
+        m.tau = 0.0;
+        m.kp = gains.kp;
+        m.kd = gains.kd;
+    }
+}
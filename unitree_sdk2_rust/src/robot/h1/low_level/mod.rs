@@ -0,0 +1,9 @@
+//! H1 and H1-2 low-level motor layouts, default gains and standing poses.
+pub mod h1_2_joints;
+pub mod h1_joints;
+
+pub use h1_2_joints::{h1_2_default_gains, set_h1_2_stand, H1_2Joint, MotorType, H1_2_NUM_MOTOR, H1_2_STAND_POSE};
+pub use h1_joints::{
+    h1_default_gains, h1_stand_pose, set_h1_stand, H1Joint, MotorGains, H1_NUM_MOTOR, H1_STRONG_GAINS,
+    H1_WEAK_GAINS,
+};
@@ -0,0 +1,3 @@
+//! H1 humanoid clients.
+pub mod loco;
+pub mod low_level;
@@ -0,0 +1,222 @@
+//! Joint tables: names, motor slots and position limits per robot model.
+//!
+//! Limits (rad) are taken from the robots' published URDFs. Wheels turn
+//! continuously and have infinite limits.
+
+// Limits are copied as rounded in the URDFs, e.g. 3.14 rather than π.
+#![allow(clippy::approx_constant)]
+
+/// One actuated joint.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct JointInfo {
+    /// URDF-style joint name, e.g. `"FR_thigh"` or `"left_knee"`.
+    pub name: &'static str,
+    /// Motor slot in `LowCmd`/`LowState`.
+    pub index: usize,
+    pub min: f32,
+    pub max: f32,
+}
+
+impl JointInfo {
+    const fn new(name: &'static str, index: usize, min: f32, max: f32) -> Self {
+        Self { name, index, min, max }
+    }
+
+    /// Whether `q` is within the joint limits.
+    pub fn contains(&self, q: f32) -> bool {
+        (self.min..=self.max).contains(&q)
+    }
+
+    /// `q` clamped to the joint limits.
+    pub fn clamp(&self, q: f32) -> f32 {
+        q.clamp(self.min, self.max)
+    }
+}
+
+/// Find a joint by name in a table.
+pub fn find_joint<'a>(table: &'a [JointInfo], name: &str) -> Option<&'a JointInfo> {
+    table.iter().find(|j| j.name == name)
+}
+
+const INF: f32 = f32::INFINITY;
+
+pub static GO2_JOINTS: [JointInfo; 12] = [
+    JointInfo::new("FR_hip", 0, -1.0472, 1.0472),
+    JointInfo::new("FR_thigh", 1, -1.5708, 3.4907),
+    JointInfo::new("FR_calf", 2, -2.7227, -0.83776),
+    JointInfo::new("FL_hip", 3, -1.0472, 1.0472),
+    JointInfo::new("FL_thigh", 4, -1.5708, 3.4907),
+    JointInfo::new("FL_calf", 5, -2.7227, -0.83776),
+    JointInfo::new("RR_hip", 6, -1.0472, 1.0472),
+    JointInfo::new("RR_thigh", 7, -0.5236, 4.5379),
+    JointInfo::new("RR_calf", 8, -2.7227, -0.83776),
+    JointInfo::new("RL_hip", 9, -1.0472, 1.0472),
+    JointInfo::new("RL_thigh", 10, -0.5236, 4.5379),
+    JointInfo::new("RL_calf", 11, -2.7227, -0.83776),
+];
+
+pub static GO2W_JOINTS: [JointInfo; 16] = [
+    GO2_JOINTS[0],
+    GO2_JOINTS[1],
+    GO2_JOINTS[2],
+    GO2_JOINTS[3],
+    GO2_JOINTS[4],
+    GO2_JOINTS[5],
+    GO2_JOINTS[6],
+    GO2_JOINTS[7],
+    GO2_JOINTS[8],
+    GO2_JOINTS[9],
+    GO2_JOINTS[10],
+    GO2_JOINTS[11],
+    JointInfo::new("FR_wheel", 12, -INF, INF),
+    JointInfo::new("FL_wheel", 13, -INF, INF),
+    JointInfo::new("RR_wheel", 14, -INF, INF),
+    JointInfo::new("RL_wheel", 15, -INF, INF),
+];
+
+pub static B2_JOINTS: [JointInfo; 12] = [
+    JointInfo::new("FR_hip", 0, -0.87, 0.87),
+    JointInfo::new("FR_thigh", 1, -0.94, 4.69),
+    JointInfo::new("FR_calf", 2, -2.82, -0.43),
+    JointInfo::new("FL_hip", 3, -0.87, 0.87),
+    JointInfo::new("FL_thigh", 4, -0.94, 4.69),
+    JointInfo::new("FL_calf", 5, -2.82, -0.43),
+    JointInfo::new("RR_hip", 6, -0.87, 0.87),
+    JointInfo::new("RR_thigh", 7, -0.94, 4.69),
+    JointInfo::new("RR_calf", 8, -2.82, -0.43),
+    JointInfo::new("RL_hip", 9, -0.87, 0.87),
+    JointInfo::new("RL_thigh", 10, -0.94, 4.69),
+    JointInfo::new("RL_calf", 11, -2.82, -0.43),
+];
+
+pub static B2W_JOINTS: [JointInfo; 16] = [
+    B2_JOINTS[0],
+    B2_JOINTS[1],
+    B2_JOINTS[2],
+    B2_JOINTS[3],
+    B2_JOINTS[4],
+    B2_JOINTS[5],
+    B2_JOINTS[6],
+    B2_JOINTS[7],
+    B2_JOINTS[8],
+    B2_JOINTS[9],
+    B2_JOINTS[10],
+    B2_JOINTS[11],
+    JointInfo::new("FR_wheel", 12, -INF, INF),
+    JointInfo::new("FL_wheel", 13, -INF, INF),
+    JointInfo::new("RR_wheel", 14, -INF, INF),
+    JointInfo::new("RL_wheel", 15, -INF, INF),
+];
+
+pub static G1_29DOF_JOINTS: [JointInfo; 29] = [
+    JointInfo::new("left_hip_pitch", 0, -2.5307, 2.8798),
+    JointInfo::new("left_hip_roll", 1, -0.5236, 2.9671),
+    JointInfo::new("left_hip_yaw", 2, -2.7576, 2.7576),
+    JointInfo::new("left_knee", 3, -0.087267, 2.8798),
+    JointInfo::new("left_ankle_pitch", 4, -0.87267, 0.5236),
+    JointInfo::new("left_ankle_roll", 5, -0.2618, 0.2618),
+    JointInfo::new("right_hip_pitch", 6, -2.5307, 2.8798),
+    JointInfo::new("right_hip_roll", 7, -2.9671, 0.5236),
+    JointInfo::new("right_hip_yaw", 8, -2.7576, 2.7576),
+    JointInfo::new("right_knee", 9, -0.087267, 2.8798),
+    JointInfo::new("right_ankle_pitch", 10, -0.87267, 0.5236),
+    JointInfo::new("right_ankle_roll", 11, -0.2618, 0.2618),
+    JointInfo::new("waist_yaw", 12, -2.618, 2.618),
+    JointInfo::new("waist_roll", 13, -0.52, 0.52),
+    JointInfo::new("waist_pitch", 14, -0.52, 0.52),
+    JointInfo::new("left_shoulder_pitch", 15, -3.0892, 2.6704),
+    JointInfo::new("left_shoulder_roll", 16, -1.5882, 2.2515),
+    JointInfo::new("left_shoulder_yaw", 17, -2.618, 2.618),
+    JointInfo::new("left_elbow", 18, -1.0472, 2.0944),
+    JointInfo::new("left_wrist_roll", 19, -1.9722, 1.9722),
+    JointInfo::new("left_wrist_pitch", 20, -1.6144, 1.6144),
+    JointInfo::new("left_wrist_yaw", 21, -1.6144, 1.6144),
+    JointInfo::new("right_shoulder_pitch", 22, -3.0892, 2.6704),
+    JointInfo::new("right_shoulder_roll", 23, -2.2515, 1.5882),
+    JointInfo::new("right_shoulder_yaw", 24, -2.618, 2.618),
+    JointInfo::new("right_elbow", 25, -1.0472, 2.0944),
+    JointInfo::new("right_wrist_roll", 26, -1.9722, 1.9722),
+    JointInfo::new("right_wrist_pitch", 27, -1.6144, 1.6144),
+    JointInfo::new("right_wrist_yaw", 28, -1.6144, 1.6144),
+];
+
+/// 23-DoF G1: waist roll/pitch and wrist pitch/yaw slots are unused.
+pub static G1_23DOF_JOINTS: [JointInfo; 23] = [
+    G1_29DOF_JOINTS[0],
+    G1_29DOF_JOINTS[1],
+    G1_29DOF_JOINTS[2],
+    G1_29DOF_JOINTS[3],
+    G1_29DOF_JOINTS[4],
+    G1_29DOF_JOINTS[5],
+    G1_29DOF_JOINTS[6],
+    G1_29DOF_JOINTS[7],
+    G1_29DOF_JOINTS[8],
+    G1_29DOF_JOINTS[9],
+    G1_29DOF_JOINTS[10],
+    G1_29DOF_JOINTS[11],
+    G1_29DOF_JOINTS[12],
+    G1_29DOF_JOINTS[15],
+    G1_29DOF_JOINTS[16],
+    G1_29DOF_JOINTS[17],
+    G1_29DOF_JOINTS[18],
+    G1_29DOF_JOINTS[19],
+    G1_29DOF_JOINTS[22],
+    G1_29DOF_JOINTS[23],
+    G1_29DOF_JOINTS[24],
+    G1_29DOF_JOINTS[25],
+    G1_29DOF_JOINTS[26],
+];
+
+/// H1 on the `unitree_go` IDL; slot 9 is unused.
+pub static H1_JOINTS: [JointInfo; 19] = [
+    JointInfo::new("right_hip_roll", 0, -0.43, 0.43),
+    JointInfo::new("right_hip_pitch", 1, -3.14, 2.53),
+    JointInfo::new("right_knee", 2, -0.26, 2.05),
+    JointInfo::new("left_hip_roll", 3, -0.43, 0.43),
+    JointInfo::new("left_hip_pitch", 4, -3.14, 2.53),
+    JointInfo::new("left_knee", 5, -0.26, 2.05),
+    JointInfo::new("torso", 6, -2.35, 2.35),
+    JointInfo::new("left_hip_yaw", 7, -0.43, 0.43),
+    JointInfo::new("right_hip_yaw", 8, -0.43, 0.43),
+    JointInfo::new("left_ankle", 10, -0.87, 0.52),
+    JointInfo::new("right_ankle", 11, -0.87, 0.52),
+    JointInfo::new("right_shoulder_pitch", 12, -2.87, 2.87),
+    JointInfo::new("right_shoulder_roll", 13, -3.11, 0.34),
+    JointInfo::new("right_shoulder_yaw", 14, -4.45, 1.3),
+    JointInfo::new("right_elbow", 15, -1.25, 2.61),
+    JointInfo::new("left_shoulder_pitch", 16, -2.87, 2.87),
+    JointInfo::new("left_shoulder_roll", 17, -0.34, 3.11),
+    JointInfo::new("left_shoulder_yaw", 18, -1.3, 4.45),
+    JointInfo::new("left_elbow", 19, -1.25, 2.61),
+];
+
+/// H1-2 on the HG IDL.
+pub static H1_2_JOINTS: [JointInfo; 27] = [
+    JointInfo::new("left_hip_yaw", 0, -0.43, 0.43),
+    JointInfo::new("left_hip_pitch", 1, -3.14, 2.5),
+    JointInfo::new("left_hip_roll", 2, -0.43, 3.14),
+    JointInfo::new("left_knee", 3, -0.12, 2.19),
+    JointInfo::new("left_ankle_pitch", 4, -0.897, 0.523),
+    JointInfo::new("left_ankle_roll", 5, -0.262, 0.262),
+    JointInfo::new("right_hip_yaw", 6, -0.43, 0.43),
+    JointInfo::new("right_hip_pitch", 7, -3.14, 2.5),
+    JointInfo::new("right_hip_roll", 8, -3.14, 0.43),
+    JointInfo::new("right_knee", 9, -0.12, 2.19),
+    JointInfo::new("right_ankle_pitch", 10, -0.897, 0.523),
+    JointInfo::new("right_ankle_roll", 11, -0.262, 0.262),
+    JointInfo::new("torso", 12, -2.35, 2.35),
+    JointInfo::new("left_shoulder_pitch", 13, -3.14, 1.57),
+    JointInfo::new("left_shoulder_roll", 14, -0.38, 3.4),
+    JointInfo::new("left_shoulder_yaw", 15, -3.01, 2.66),
+    JointInfo::new("left_elbow", 16, -2.53, 1.6),
+    JointInfo::new("left_wrist_roll", 17, -2.967, 2.967),
+    JointInfo::new("left_wrist_pitch", 18, -0.471, 0.349),
+    JointInfo::new("left_wrist_yaw", 19, -1.012, 1.012),
+    JointInfo::new("right_shoulder_pitch", 20, -3.14, 1.57),
+    JointInfo::new("right_shoulder_roll", 21, -3.4, 0.38),
+    JointInfo::new("right_shoulder_yaw", 22, -2.66, 3.01),
+    JointInfo::new("right_elbow", 23, -2.53, 1.6),
+    JointInfo::new("right_wrist_roll", 24, -2.967, 2.967),
+    JointInfo::new("right_wrist_pitch", 25, -0.471, 0.349),
+    JointInfo::new("right_wrist_yaw", 26, -1.012, 1.012),
+];
@@ -0,0 +1,6 @@
+//! Robot identification and per-model joint tables.
+pub mod joint_table;
+pub mod robot_info;
+
+pub use joint_table::{find_joint, JointInfo};
+pub use robot_info::{IdlKind, RobotInfo, RobotModel};
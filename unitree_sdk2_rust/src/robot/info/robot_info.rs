@@ -0,0 +1,281 @@
+//! RobotInfo - identify the connected robot from `rt/lowstate`.
+//!
+//! Quadrupeds and the original H1 publish `unitree_go` `LowState`; G1 and
+//! H1-2 publish `unitree_hg` `LowState`. DDS rejects readers of two types
+//! on one topic, so [`RobotInfo::detect`] probes `rt/lowstate` with one IDL
+//! at a time; the IDL that delivers a state is reliable.
+//!
+//! The model is only narrowed down by an unverified heuristic: a motor slot
+//! counts as populated when it reports a temperature, and the populated
+//! slots are matched against the joint tables.
+//!
+//! - **unitree_go**: motors in slots 16-19 mean H1; motors in slots 12-15
+//!   (wheels) mean Go2W or B2W; otherwise Go2 or B2.
+//! - **unitree_hg**: a motor in slot 28 (right wrist yaw) means G1 29-DoF;
+//!   otherwise a motor in slot 20 means H1-2, else G1 23-DoF.
+//!
+//! `mode_machine` is reported as is; the SDK documents no mapping from it
+//! to a model. When the heuristic leaves more than one candidate, or no
+//! slot reports a temperature, the model is unknown.
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::channel::{ChannelFactory, ChannelSubscriber};
+use crate::error::{Result, SdkError};
+use crate::idl::{go2, hg};
+use super::joint_table::*;
+
+pub const TOPIC_LOWSTATE: &str = "rt/lowstate";
+
+/// Which IDL the robot's low-level topics use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IdlKind {
+    /// `unitree_go` messages (`crate::idl::go2`).
+    Go,
+    /// `unitree_hg` messages (`crate::idl::hg`).
+    Hg,
+}
+
+/// Robot model and variant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RobotModel {
+    Go2,
+    Go2W,
+    B2,
+    B2W,
+    G1Dof23,
+    G1Dof29,
+    H1,
+    H1_2,
+}
+
+impl RobotModel {
+    pub const ALL: [RobotModel; 8] = [
+        RobotModel::Go2,
+        RobotModel::Go2W,
+        RobotModel::B2,
+        RobotModel::B2W,
+        RobotModel::G1Dof23,
+        RobotModel::G1Dof29,
+        RobotModel::H1,
+        RobotModel::H1_2,
+    ];
+
+    /// Human-readable name, e.g. `"G1 29-DoF"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            RobotModel::Go2 => "Go2",
+            RobotModel::Go2W => "Go2W",
+            RobotModel::B2 => "B2",
+            RobotModel::B2W => "B2W",
+            RobotModel::G1Dof23 => "G1 23-DoF",
+            RobotModel::G1Dof29 => "G1 29-DoF",
+            RobotModel::H1 => "H1",
+            RobotModel::H1_2 => "H1-2",
+        }
+    }
+
+    /// IDL used on the low-level topics.
+    pub fn idl(self) -> IdlKind {
+        match self {
+            RobotModel::Go2 | RobotModel::Go2W | RobotModel::B2 | RobotModel::B2W | RobotModel::H1 => {
+                IdlKind::Go
+            }
+            RobotModel::G1Dof23 | RobotModel::G1Dof29 | RobotModel::H1_2 => IdlKind::Hg,
+        }
+    }
+
+    /// Joint table with motor slots and limits.
+    pub fn joints(self) -> &'static [JointInfo] {
+        match self {
+            RobotModel::Go2 => &GO2_JOINTS,
+            RobotModel::Go2W => &GO2W_JOINTS,
+            RobotModel::B2 => &B2_JOINTS,
+            RobotModel::B2W => &B2W_JOINTS,
+            RobotModel::G1Dof23 => &G1_23DOF_JOINTS,
+            RobotModel::G1Dof29 => &G1_29DOF_JOINTS,
+            RobotModel::H1 => &H1_JOINTS,
+            RobotModel::H1_2 => &H1_2_JOINTS,
+        }
+    }
+
+    /// Number of actuated joints.
+    pub fn num_joints(self) -> usize {
+        self.joints().len()
+    }
+}
+
+impl fmt::Display for RobotModel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Identity of a connected robot.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RobotInfo {
+    /// IDL the state arrived on.
+    pub idl: IdlKind,
+    /// Models matching the populated motor slots. Unverified; see the
+    /// module docs.
+    pub candidates: Vec<RobotModel>,
+    /// Serial number, hex-encoded from `LowState::sn`. `unitree_hg` states
+    /// do not carry one.
+    pub serial_number: Option<String>,
+    /// Firmware version from `LowState::version`, as `"major.minor"`.
+    pub firmware_version: String,
+    /// `mode_machine` reported by HG robots.
+    pub mode_machine: Option<u8>,
+}
+
+/// Models on `idl` whose layout matches the populated slots; every model
+/// on `idl` if none is populated.
+fn candidates(idl: IdlKind, populated: impl Fn(usize) -> bool, num_slots: usize) -> Vec<RobotModel> {
+    if !(0..num_slots).any(&populated) {
+        return RobotModel::ALL.into_iter().filter(|m| m.idl() == idl).collect();
+    }
+    match idl {
+        IdlKind::Go if (16..20).any(&populated) => vec![RobotModel::H1],
+        IdlKind::Go if (12..16).any(&populated) => vec![RobotModel::Go2W, RobotModel::B2W],
+        IdlKind::Go => vec![RobotModel::Go2, RobotModel::B2],
+        IdlKind::Hg if populated(28) => vec![RobotModel::G1Dof29],
+        IdlKind::Hg if populated(20) => vec![RobotModel::H1_2],
+        IdlKind::Hg => vec![RobotModel::G1Dof23],
+    }
+}
+
+impl RobotInfo {
+    /// Describe a `unitree_go` `LowState`.
+    pub fn from_go2_state(state: &go2::LowState) -> Self {
+        let populated = |i: usize| state.motor_state[i].temperature != 0;
+        let sn = state.sn;
+        Self {
+            idl: IdlKind::Go,
+            candidates: candidates(IdlKind::Go, populated, state.motor_state.len()),
+            serial_number: (sn != [0, 0]).then(|| format!("{:08X}{:08X}", sn[0], sn[1])),
+            firmware_version: format!("{}.{}", state.version[0], state.version[1]),
+            mode_machine: None,
+        }
+    }
+
+    /// Describe a `unitree_hg` `LowState`.
+    pub fn from_hg_state(state: &hg::LowState) -> Self {
+        let populated = |i: usize| state.motor_state[i].temperature != [0, 0];
+        Self {
+            idl: IdlKind::Hg,
+            candidates: candidates(IdlKind::Hg, populated, state.motor_state.len()),
+            serial_number: None,
+            firmware_version: format!("{}.{}", state.version[0], state.version[1]),
+            mode_machine: Some(state.mode_machine),
+        }
+    }
+
+    /// The model, if exactly one candidate is left.
+    pub fn model(&self) -> Option<RobotModel> {
+        match self.candidates.as_slice() {
+            [model] => Some(*model),
+            _ => None,
+        }
+    }
+
+    /// Joint table of the model, if it is known.
+    pub fn joints(&self) -> Option<&'static [JointInfo]> {
+        self.model().map(RobotModel::joints)
+    }
+
+    /// Subscribe to `rt/lowstate` with `S` for up to `timeout` and describe
+    /// the first state received. The subscriber is closed before returning.
+    fn probe<S, F>(timeout: Duration, describe: F) -> Result<Option<Self>>
+    where
+        S: Clone + Send + 'static,
+        F: Fn(&S) -> RobotInfo + Send + 'static,
+    {
+        let found: Arc<Mutex<Option<RobotInfo>>> = Arc::new(Mutex::new(None));
+        let mut sub: ChannelSubscriber<S> =
+            ChannelFactory::instance().lock().unwrap().create_subscriber(TOPIC_LOWSTATE);
+        {
+            let found = Arc::clone(&found);
+            sub.init_channel(move |state: &S| {
+                found.lock().unwrap().get_or_insert_with(|| describe(state));
+            })?;
+        }
+
+        let start = Instant::now();
+        let info = loop {
+            if let Some(info) = found.lock().unwrap().clone() {
+                break Some(info);
+            }
+            if start.elapsed() > timeout {
+                break None;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        };
+        sub.close_channel();
+        Ok(info)
+    }
+
+    /// Probe `rt/lowstate` as `unitree_go`, then as `unitree_hg`, each for
+    /// half of `timeout`, and describe the first state received. Fails with
+    /// `Timeout` if neither delivers one.
+    pub fn detect(timeout: Duration) -> Result<Self> {
+        let half = timeout / 2;
+        let info = match Self::probe(half, RobotInfo::from_go2_state)? {
+            Some(info) => info,
+            None => Self::probe(half, RobotInfo::from_hg_state)?.ok_or(SdkError::Timeout)?,
+        };
+        log::info!(
+            "Detected {:?} robot: candidates {:?} (firmware {}, sn {})",
+            info.idl,
+            info.candidates,
+            info.firmware_version,
+            info.serial_number.as_deref().unwrap_or("n/a")
+        );
+        Ok(info)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hg_layouts() {
+        let mut state = hg::LowState::default();
+        let info = RobotInfo::from_hg_state(&state);
+        assert_eq!(info.model(), None);
+        assert_eq!(info.candidates, [RobotModel::G1Dof23, RobotModel::G1Dof29, RobotModel::H1_2]);
+
+        for i in 0..27 {
+            state.motor_state[i].temperature = [40, 40];
+        }
+        assert_eq!(RobotInfo::from_hg_state(&state).model(), Some(RobotModel::H1_2));
+        state.motor_state[28].temperature = [40, 40];
+        assert_eq!(RobotInfo::from_hg_state(&state).model(), Some(RobotModel::G1Dof29));
+    }
+
+    #[test]
+    fn go_quadrupeds_stay_ambiguous() {
+        let mut state = go2::LowState::default();
+        for i in 0..12 {
+            state.motor_state[i].temperature = 40;
+        }
+        let info = RobotInfo::from_go2_state(&state);
+        assert_eq!(info.idl, IdlKind::Go);
+        assert_eq!(info.model(), None);
+        assert_eq!(info.candidates, [RobotModel::Go2, RobotModel::B2]);
+        assert!(info.joints().is_none());
+
+        state.motor_state[12].temperature = 40;
+        assert_eq!(RobotInfo::from_go2_state(&state).candidates, [RobotModel::Go2W, RobotModel::B2W]);
+        state.motor_state[16].temperature = 40;
+        assert_eq!(RobotInfo::from_go2_state(&state).model(), Some(RobotModel::H1));
+    }
+
+    #[test]
+    fn detect_times_out_without_state() {
+        let result = RobotInfo::detect(Duration::from_millis(40));
+        assert!(matches!(result, Err(SdkError::Timeout)));
+    }
+}
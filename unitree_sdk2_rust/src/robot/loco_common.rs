@@ -0,0 +1,227 @@
+//! Request plumbing shared by the humanoid locomotion clients.
+//!
+//! G1 and H1 expose the same locomotion service shape under different
+//! service names and API ids. [`LocoCore`] implements the common calls
+//! against a [`LocoApi`] id table; `LocoClient` and `H1LocoClient` add their
+//! robot-specific calls on top.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::rpc::{decode, JsonData, RpcClient};
+
+#[derive(Debug, Serialize)]
+struct VelocityCommand {
+    velocity: [f32; 3],
+    duration: f32,
+}
+
+/// Service name, version, API ids and FSM ids of one locomotion service.
+pub(crate) struct LocoApi {
+    pub service_name: &'static str,
+    pub version: &'static str,
+    pub get_fsm_id: i32,
+    pub get_fsm_mode: i32,
+    pub get_balance_mode: i32,
+    pub get_swing_height: i32,
+    pub get_stand_height: i32,
+    pub get_phase: i32,
+    pub set_fsm_id: i32,
+    pub set_balance_mode: i32,
+    pub set_swing_height: i32,
+    pub set_stand_height: i32,
+    pub set_velocity: i32,
+    pub set_arm_task: i32,
+    pub fsm_zero_torque: i32,
+    pub fsm_damp: i32,
+    pub fsm_stand_up: i32,
+    pub fsm_start: i32,
+    /// Arm task ids that extend and withdraw the hand for a handshake.
+    pub shake_hand_tasks: [i32; 2],
+    /// Velocity command duration used for continuous moves, in seconds.
+    pub continuous_move_duration: f32,
+}
+
+/// Common locomotion calls over an [`RpcClient`].
+pub(crate) struct LocoCore {
+    pub rpc: RpcClient,
+    api: &'static LocoApi,
+    continuous_move: bool,
+    first_shake_hand_stage: bool,
+}
+
+impl LocoCore {
+    pub fn new(api: &'static LocoApi) -> Self {
+        Self {
+            rpc: RpcClient::new(api.service_name, false),
+            api,
+            continuous_move: false,
+            first_shake_hand_stage: true,
+        }
+    }
+
+    pub fn set_timeout(&mut self, timeout: f32) {
+        self.rpc.set_timeout(timeout);
+    }
+
+    /// Register the common API ids plus `extra`, and log as `client`.
+    pub fn init(&mut self, client: &str, extra: &[i32]) {
+        let api = self.api;
+        self.rpc.set_api_version(api.version);
+        for api_id in [
+            api.get_fsm_id,
+            api.get_fsm_mode,
+            api.get_balance_mode,
+            api.get_swing_height,
+            api.get_stand_height,
+            api.get_phase,
+            api.set_fsm_id,
+            api.set_balance_mode,
+            api.set_swing_height,
+            api.set_stand_height,
+            api.set_velocity,
+            api.set_arm_task,
+        ]
+        .into_iter()
+        .chain(extra.iter().copied())
+        {
+            self.rpc.register_api(api_id, 0);
+        }
+        log::info!(
+            "{} init: service={} version={}",
+            client,
+            api.service_name,
+            api.version
+        );
+    }
+
+    pub fn get<T: DeserializeOwned>(&self, api_id: i32) -> Result<T, i32> {
+        let data = self.rpc.call(api_id, "{}")?;
+        Ok(decode::<JsonData<T>>(&data)?.data)
+    }
+
+    pub fn set<T: Serialize>(&self, api_id: i32, value: T) -> i32 {
+        self.rpc.call_no_data(api_id, &JsonData::encode(value))
+    }
+
+    pub fn get_fsm_id(&self) -> Result<i32, i32> {
+        self.get(self.api.get_fsm_id)
+    }
+
+    pub fn get_fsm_mode(&self) -> Result<i32, i32> {
+        self.get(self.api.get_fsm_mode)
+    }
+
+    pub fn get_balance_mode(&self) -> Result<i32, i32> {
+        self.get(self.api.get_balance_mode)
+    }
+
+    pub fn get_swing_height(&self) -> Result<f32, i32> {
+        self.get(self.api.get_swing_height)
+    }
+
+    pub fn get_stand_height(&self) -> Result<f32, i32> {
+        self.get(self.api.get_stand_height)
+    }
+
+    pub fn get_phase(&self) -> Result<Vec<f32>, i32> {
+        self.get(self.api.get_phase)
+    }
+
+    pub fn set_fsm_id(&self, fsm_id: i32) -> i32 {
+        self.set(self.api.set_fsm_id, fsm_id)
+    }
+
+    pub fn set_balance_mode(&self, balance_mode: i32) -> i32 {
+        self.set(self.api.set_balance_mode, balance_mode)
+    }
+
+    pub fn set_swing_height(&self, swing_height: f32) -> i32 {
+        self.set(self.api.set_swing_height, swing_height)
+    }
+
+    pub fn set_stand_height(&self, stand_height: f32) -> i32 {
+        self.set(self.api.set_stand_height, stand_height)
+    }
+
+    pub fn set_velocity(&self, vx: f32, vy: f32, omega: f32, duration: f32) -> i32 {
+        let cmd = VelocityCommand {
+            velocity: [vx, vy, omega],
+            duration,
+        };
+        let p = serde_json::to_string(&cmd).unwrap_or_default();
+        self.rpc.call_no_data(self.api.set_velocity, &p)
+    }
+
+    pub fn set_task_id(&self, task_id: i32) -> i32 {
+        self.set(self.api.set_arm_task, task_id)
+    }
+
+    pub fn damp(&self) -> i32 {
+        self.set_fsm_id(self.api.fsm_damp)
+    }
+
+    pub fn start(&self) -> i32 {
+        self.set_fsm_id(self.api.fsm_start)
+    }
+
+    pub fn stand_up(&self) -> i32 {
+        self.set_fsm_id(self.api.fsm_stand_up)
+    }
+
+    pub fn zero_torque(&self) -> i32 {
+        self.set_fsm_id(self.api.fsm_zero_torque)
+    }
+
+    pub fn stop_move(&self) -> i32 {
+        self.set_velocity(0.0, 0.0, 0.0, 1.0)
+    }
+
+    pub fn high_stand(&self) -> i32 {
+        self.set_stand_height(u32::MAX as f32)
+    }
+
+    pub fn low_stand(&self) -> i32 {
+        self.set_stand_height(u32::MIN as f32)
+    }
+
+    pub fn move_with(&self, vx: f32, vy: f32, vyaw: f32, continuous: bool) -> i32 {
+        let duration = if continuous { self.api.continuous_move_duration } else { 1.0 };
+        self.set_velocity(vx, vy, vyaw, duration)
+    }
+
+    pub fn move_cmd(&self, vx: f32, vy: f32, vyaw: f32) -> i32 {
+        self.move_with(vx, vy, vyaw, self.continuous_move)
+    }
+
+    pub fn balance_stand(&self) -> i32 {
+        self.set_balance_mode(0)
+    }
+
+    pub fn continuous_gait(&self, flag: bool) -> i32 {
+        self.set_balance_mode(flag as i32)
+    }
+
+    pub fn switch_move_mode(&mut self, flag: bool) -> i32 {
+        self.continuous_move = flag;
+        0
+    }
+
+    pub fn shake_hand(&mut self, stage: Option<u8>) -> i32 {
+        let [extend, withdraw] = self.api.shake_hand_tasks;
+        match stage {
+            Some(0) => {
+                self.first_shake_hand_stage = false;
+                self.set_task_id(extend)
+            }
+            Some(1) => {
+                self.first_shake_hand_stage = true;
+                self.set_task_id(withdraw)
+            }
+            _ => {
+                self.first_shake_hand_stage = !self.first_shake_hand_stage;
+                self.set_task_id(if self.first_shake_hand_stage { withdraw } else { extend })
+            }
+        }
+    }
+}
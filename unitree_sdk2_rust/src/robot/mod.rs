@@ -1,2 +1,9 @@
 //! Robot client implementations.
+pub mod a2;
+pub mod b2;
+pub mod g1;
 pub mod go2;
+pub mod h1;
+pub mod info;
+pub(crate) mod loco_common;
+pub mod wheeled;
@@ -0,0 +1,135 @@
+//! Joints of the wheeled quadrupeds (Go2W, B2W).
+//!
+//! Both use the `unitree_go` IDL with the twelve leg joints of their legged
+//! counterparts in slots 0-11 and the four wheels in slots 12-15.
+
+use crate::robot::info::{JointInfo, RobotModel};
+
+/// Number of actuated joints, legs plus wheels.
+pub const WHEELED_NUM_JOINTS: usize = 16;
+/// Number of leg joints.
+pub const WHEELED_NUM_LEG_JOINTS: usize = 12;
+
+/// Which wheeled robot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WheeledModel {
+    Go2W,
+    B2W,
+}
+
+impl WheeledModel {
+    /// The matching [`RobotModel`].
+    pub fn robot_model(self) -> RobotModel {
+        match self {
+            WheeledModel::Go2W => RobotModel::Go2W,
+            WheeledModel::B2W => RobotModel::B2W,
+        }
+    }
+
+    /// Joint table with limits; wheels have infinite limits.
+    pub fn joints(self) -> &'static [JointInfo] {
+        self.robot_model().joints()
+    }
+
+    /// Wheel velocity gain used by the C++ stand examples: 5 in
+    /// `go2w_stand_example.cpp`, 10 in `b2w_stand_example.cpp`.
+    pub fn wheel_kd(self) -> f32 {
+        match self {
+            WheeledModel::Go2W => 5.0,
+            WheeledModel::B2W => 10.0,
+        }
+    }
+
+    /// Motion switcher alias of the wheeled sport service.
+    pub fn motion_mode(self) -> &'static str {
+        match self {
+            WheeledModel::Go2W => "ai-w",
+            WheeledModel::B2W => "normal-w",
+        }
+    }
+}
+
+impl TryFrom<RobotModel> for WheeledModel {
+    type Error = RobotModel;
+
+    fn try_from(value: RobotModel) -> Result<Self, Self::Error> {
+        match value {
+            RobotModel::Go2W => Ok(WheeledModel::Go2W),
+            RobotModel::B2W => Ok(WheeledModel::B2W),
+            other => Err(other),
+        }
+    }
+}
+
+/// Go2W/B2W joints, in motor order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(usize)]
+pub enum WheeledJoint {
+    FrHip = 0,
+    FrThigh = 1,
+    FrCalf = 2,
+    FlHip = 3,
+    FlThigh = 4,
+    FlCalf = 5,
+    RrHip = 6,
+    RrThigh = 7,
+    RrCalf = 8,
+    RlHip = 9,
+    RlThigh = 10,
+    RlCalf = 11,
+    FrWheel = 12,
+    FlWheel = 13,
+    RrWheel = 14,
+    RlWheel = 15,
+}
+
+impl WheeledJoint {
+    pub const ALL: [WheeledJoint; WHEELED_NUM_JOINTS] = [
+        WheeledJoint::FrHip,
+        WheeledJoint::FrThigh,
+        WheeledJoint::FrCalf,
+        WheeledJoint::FlHip,
+        WheeledJoint::FlThigh,
+        WheeledJoint::FlCalf,
+        WheeledJoint::RrHip,
+        WheeledJoint::RrThigh,
+        WheeledJoint::RrCalf,
+        WheeledJoint::RlHip,
+        WheeledJoint::RlThigh,
+        WheeledJoint::RlCalf,
+        WheeledJoint::FrWheel,
+        WheeledJoint::FlWheel,
+        WheeledJoint::RrWheel,
+        WheeledJoint::RlWheel,
+    ];
+
+    pub const WHEELS: [WheeledJoint; 4] = [
+        WheeledJoint::FrWheel,
+        WheeledJoint::FlWheel,
+        WheeledJoint::RrWheel,
+        WheeledJoint::RlWheel,
+    ];
+
+    /// Motor index in `LowCmd`/`LowState`.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Whether this is a wheel.
+    pub fn is_wheel(self) -> bool {
+        self.index() >= WHEELED_NUM_LEG_JOINTS
+    }
+
+    /// Name, limits and slot of this joint on `model`.
+    pub fn info(self, model: WheeledModel) -> &'static JointInfo {
+        &model.joints()[self.index()]
+    }
+}
+
+impl TryFrom<usize> for WheeledJoint {
+    type Error = usize;
+
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        WheeledJoint::ALL.get(value).copied().ok_or(value)
+    }
+}
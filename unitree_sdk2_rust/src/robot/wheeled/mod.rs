@@ -0,0 +1,8 @@
+//! Wheeled quadrupeds (Go2W, B2W): joints, sport client and wheel control.
+pub mod joints;
+pub mod sport_client;
+pub mod wheels;
+
+pub use joints::{WheeledJoint, WheeledModel, WHEELED_NUM_JOINTS, WHEELED_NUM_LEG_JOINTS};
+pub use sport_client::WheeledSportClient;
+pub use wheels::{set_wheel_velocities, set_wheel_velocity, stop_wheels, WheelRamp, WheelSpeeds};
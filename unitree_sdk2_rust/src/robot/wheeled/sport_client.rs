@@ -0,0 +1,135 @@
+//! WheeledSportClient - sport-mode API for Go2W and B2W.
+//!
+//! The wheeled robots run the sport service of their legged counterparts
+//! (Go2W the Go2 service, B2W the B2 one) once the motion switcher has
+//! selected the wheeled mode ([`WheeledModel::motion_mode`]). This client
+//! wraps the matching [`SportClient`](crate::robot::go2::sport::SportClient)
+//! and exposes the calls both services share.
+
+use crate::robot::{b2, go2};
+use crate::rpc::rpc_error::ROBOT_ERR_CLIENT_API_NOT_REG;
+use super::joints::WheeledModel;
+
+enum Inner {
+    Go2(go2::sport::SportClient),
+    B2(b2::sport::SportClient),
+}
+
+/// Sport client for a wheeled robot.
+pub struct WheeledSportClient {
+    model: WheeledModel,
+    inner: Inner,
+}
+
+macro_rules! dispatch {
+    ($self:ident, $c:ident => $call:expr) => {
+        match &$self.inner {
+            Inner::Go2($c) => $call,
+            Inner::B2($c) => $call,
+        }
+    };
+}
+
+impl WheeledSportClient {
+    /// Create a new `WheeledSportClient` for `model`.
+    pub fn new(model: WheeledModel, enable_lease: bool) -> Self {
+        let inner = match model {
+            WheeledModel::Go2W => Inner::Go2(go2::sport::SportClient::new(enable_lease)),
+            WheeledModel::B2W => Inner::B2(b2::sport::SportClient::new(enable_lease)),
+        };
+        Self { model, inner }
+    }
+
+    /// Robot this client talks to.
+    pub fn model(&self) -> WheeledModel {
+        self.model
+    }
+
+    /// Set the RPC timeout in seconds.
+    pub fn set_timeout(&mut self, timeout: f32) {
+        match &mut self.inner {
+            Inner::Go2(c) => c.set_timeout(timeout),
+            Inner::B2(c) => c.set_timeout(timeout),
+        }
+    }
+
+    /// Initialize the client.
+    pub fn init(&mut self) {
+        match &mut self.inner {
+            Inner::Go2(c) => c.init(),
+            Inner::B2(c) => c.init(),
+        }
+        log::info!("WheeledSportClient init: model={:?}", self.model);
+    }
+
+    /// Damp all motors.
+    pub fn damp(&self) -> i32 {
+        dispatch!(self, c => c.damp())
+    }
+
+    /// Enter balance stand mode.
+    pub fn balance_stand(&self) -> i32 {
+        dispatch!(self, c => c.balance_stand())
+    }
+
+    /// Stop all movement.
+    pub fn stop_move(&self) -> i32 {
+        dispatch!(self, c => c.stop_move())
+    }
+
+    /// Stand up.
+    pub fn stand_up(&self) -> i32 {
+        dispatch!(self, c => c.stand_up())
+    }
+
+    /// Stand down (lie down).
+    pub fn stand_down(&self) -> i32 {
+        dispatch!(self, c => c.stand_down())
+    }
+
+    /// Recovery stand from fallen state.
+    pub fn recovery_stand(&self) -> i32 {
+        dispatch!(self, c => c.recovery_stand())
+    }
+
+    /// Move with velocity (m/s for vx/vy, rad/s for vyaw).
+    pub fn move_cmd(&self, vx: f32, vy: f32, vyaw: f32) -> i32 {
+        dispatch!(self, c => c.move_cmd(vx, vy, vyaw))
+    }
+
+    /// Set speed level.
+    pub fn speed_level(&self, level: i32) -> i32 {
+        dispatch!(self, c => c.speed_level(level))
+    }
+
+    /// Set body attitude (rad).
+    pub fn euler(&self, roll: f32, pitch: f32, yaw: f32) -> i32 {
+        dispatch!(self, c => c.euler(roll, pitch, yaw))
+    }
+
+    /// Switch gait (B2W only; see [`b2::sport::SportClient::switch_gait`]).
+    /// Returns `ROBOT_ERR_CLIENT_API_NOT_REG` on Go2W, whose sport service
+    /// has no gait switch.
+    pub fn switch_gait(&self, gait: i32) -> i32 {
+        match &self.inner {
+            Inner::Go2(_) => ROBOT_ERR_CLIENT_API_NOT_REG,
+            Inner::B2(c) => c.switch_gait(gait),
+        }
+    }
+
+    /// The underlying Go2 client, for Go2W-only calls.
+    pub fn as_go2(&self) -> Option<&go2::sport::SportClient> {
+        match &self.inner {
+            Inner::Go2(c) => Some(c),
+            Inner::B2(_) => None,
+        }
+    }
+
+    /// The underlying B2 client, for B2W-only calls.
+    pub fn as_b2(&self) -> Option<&b2::sport::SportClient> {
+        match &self.inner {
+            Inner::Go2(_) => None,
+            Inner::B2(c) => Some(c),
+        }
+    }
+}
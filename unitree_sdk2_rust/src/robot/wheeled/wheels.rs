@@ -0,0 +1,79 @@
+//! Low-level wheel velocity commands for Go2W/B2W.
+//!
+//! Wheels are driven in velocity mode: `kp = 0` so there is no position
+//! target, `dq` is the wheel speed and `kd` the velocity gain, as in the
+//! `go2w_stand_example` and `b2w_stand_example`. The gain differs per
+//! robot; see [`WheeledModel::wheel_kd`](super::WheeledModel::wheel_kd).
+
+use crate::idl::go2::LowCmd;
+use super::joints::WheeledJoint;
+
+/// Wheel speeds (rad/s), in `WheeledJoint::WHEELS` order: FR, FL, RR, RL.
+pub type WheelSpeeds = [f32; 4];
+
+/// Put one wheel in velocity mode at `dq` rad/s.
+pub fn set_wheel_velocity(cmd: &mut LowCmd, wheel: WheeledJoint, dq: f32, kd: f32) {
+    debug_assert!(wheel.is_wheel(), "{wheel:?} is not a wheel");
+    let m = &mut cmd.motor_cmd[wheel.index()];
+    m.mode = 0x01;
+    m.q = 0.0;
+    m.kp = 0.0;
+    m.dq = dq;
+    m.kd = kd;
+    m.tau = 0.0;
+}
+
+/// Put every wheel in velocity mode.
+pub fn set_wheel_velocities(cmd: &mut LowCmd, speeds: WheelSpeeds, kd: f32) {
+    for (wheel, dq) in WheeledJoint::WHEELS.into_iter().zip(speeds) {
+        set_wheel_velocity(cmd, wheel, dq, kd);
+    }
+}
+
+/// Hold every wheel at zero speed (velocity damping).
+pub fn stop_wheels(cmd: &mut LowCmd, kd: f32) {
+    set_wheel_velocities(cmd, [0.0; 4], kd);
+}
+
+/// Ramps wheel speeds towards targets with a bounded acceleration, so
+/// speed changes do not jolt the robot.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WheelRamp {
+    /// Max speed change per second (rad/s²).
+    pub max_accel: f32,
+    /// Velocity gain used for the commands.
+    pub kd: f32,
+    current: WheelSpeeds,
+    target: WheelSpeeds,
+}
+
+impl WheelRamp {
+    /// Start from standstill.
+    pub fn new(max_accel: f32, kd: f32) -> Self {
+        Self {
+            max_accel,
+            kd,
+            current: [0.0; 4],
+            target: [0.0; 4],
+        }
+    }
+
+    /// Set the target speeds.
+    pub fn set_target(&mut self, speeds: WheelSpeeds) {
+        self.target = speeds;
+    }
+
+    /// Speeds commanded on the last step.
+    pub fn current(&self) -> WheelSpeeds {
+        self.current
+    }
+
+    /// Advance by `dt` seconds and write the wheel commands into `cmd`.
+    pub fn step(&mut self, cmd: &mut LowCmd, dt: f32) {
+        let max_delta = self.max_accel * dt;
+        for (current, target) in self.current.iter_mut().zip(self.target) {
+            *current += (target - *current).clamp(-max_delta, max_delta);
+        }
+        set_wheel_velocities(cmd, self.current, self.kd);
+    }
+}
@@ -0,0 +1,7 @@
+//! Request/response RPC layer shared by the service clients.
+pub mod rpc_client;
+pub mod rpc_data;
+pub mod rpc_error;
+
+pub use rpc_client::{decode, RpcClient};
+pub use rpc_data::JsonData;
@@ -0,0 +1,143 @@
+//! RpcClient - request/response plumbing behind every service client.
+//!
+//! Each service client owns an `RpcClient` bound to its service name and API
+//! version, registers the API ids it uses, and calls them with a JSON
+//! parameter string. Responses are returned as the raw JSON data string;
+//! [`decode`] turns them into typed values.
+//!
+//! TODO: Real implementation would use DDS RPC over CycloneDDS. Calls
+//! currently log their invocation and return an empty JSON object.
+
+use std::collections::HashMap;
+
+use serde::de::DeserializeOwned;
+
+use super::rpc_error::*;
+
+/// Service-bound RPC client.
+pub struct RpcClient {
+    service_name: String,
+    api_version: String,
+    enable_lease: bool,
+    timeout: f32,
+    apis: HashMap<i32, i32>,
+}
+
+impl RpcClient {
+    /// Create a client for `service_name`.
+    pub fn new(service_name: &str, enable_lease: bool) -> Self {
+        Self {
+            service_name: service_name.to_string(),
+            api_version: String::new(),
+            enable_lease,
+            timeout: 10.0,
+            apis: HashMap::new(),
+        }
+    }
+
+    /// Service this client talks to.
+    pub fn service_name(&self) -> &str {
+        &self.service_name
+    }
+
+    /// Set the API version sent with each request.
+    pub fn set_api_version(&mut self, api_version: &str) {
+        self.api_version = api_version.to_string();
+    }
+
+    /// API version sent with each request.
+    pub fn api_version(&self) -> &str {
+        &self.api_version
+    }
+
+    /// Whether requests are sent under a lease.
+    pub fn enable_lease(&self) -> bool {
+        self.enable_lease
+    }
+
+    /// Set the RPC timeout in seconds.
+    pub fn set_timeout(&mut self, timeout: f32) {
+        self.timeout = timeout;
+    }
+
+    /// RPC timeout in seconds.
+    pub fn timeout(&self) -> f32 {
+        self.timeout
+    }
+
+    /// Register an API id so it can be called, with the given priority.
+    pub fn register_api(&mut self, api_id: i32, priority: i32) {
+        self.apis.insert(api_id, priority);
+    }
+
+    fn check_api(&self, api_id: i32) -> Result<i32, i32> {
+        self.apis.get(&api_id).copied().ok_or_else(|| {
+            log::error!("{}: api_id={} is not registered", self.service_name, api_id);
+            ROBOT_ERR_CLIENT_API_NOT_REG
+        })
+    }
+
+    /// Call an API with a JSON parameter. Returns the response data or
+    /// `Err(api_error_code)`.
+    pub fn call(&self, api_id: i32, parameter: &str) -> Result<String, i32> {
+        let priority = self.check_api(api_id)?;
+        log::info!(
+            "{} call: api_id={} priority={} parameter={}",
+            self.service_name,
+            api_id,
+            priority,
+            parameter
+        );
+        // Stub: empty response object
+        Ok("{}".to_string())
+    }
+
+    /// Call an API with a JSON parameter and a binary payload. Returns the
+    /// response data or `Err(api_error_code)`.
+    pub fn call_with_binary(&self, api_id: i32, parameter: &str, binary: &[u8]) -> Result<String, i32> {
+        let priority = self.check_api(api_id)?;
+        log::info!(
+            "{} call: api_id={} priority={} parameter={} binary_len={}",
+            self.service_name,
+            api_id,
+            priority,
+            parameter,
+            binary.len()
+        );
+        // Stub: empty response object
+        Ok("{}".to_string())
+    }
+
+    /// Call an API whose response is a binary payload (e.g. a JPEG image).
+    /// Returns the payload or `Err(api_error_code)`.
+    pub fn call_for_binary(&self, api_id: i32, parameter: &str) -> Result<Vec<u8>, i32> {
+        let priority = self.check_api(api_id)?;
+        log::info!(
+            "{} call: api_id={} priority={} parameter={}",
+            self.service_name,
+            api_id,
+            priority,
+            parameter
+        );
+        // Stub: empty payload
+        Ok(Vec::new())
+    }
+
+    /// Call an API whose response carries no data. Returns `ROBOT_OK` or
+    /// the API error code.
+    pub fn call_no_data(&self, api_id: i32, parameter: &str) -> i32 {
+        match self.call(api_id, parameter) {
+            Ok(_) => ROBOT_OK,
+            Err(code) => code,
+        }
+    }
+}
+
+/// Decode a JSON response. Malformed or empty data maps to
+/// `ROBOT_ERR_CLIENT_API_DATA`.
+pub fn decode<T: DeserializeOwned>(data: &str) -> Result<T, i32> {
+    serde_json::from_str(data).map_err(|e| {
+        log::error!("Failed to decode response data: {} (data={:?})", e, data);
+        ROBOT_ERR_CLIENT_API_DATA
+    })
+}
@@ -0,0 +1,15 @@
+//! Common JSON payload shapes used by service APIs.
+use serde::{Deserialize, Serialize};
+
+/// A single value wrapped as `{"data": value}`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct JsonData<T> {
+    pub data: T,
+}
+
+impl<T: Serialize> JsonData<T> {
+    /// Encode `value` as `{"data": value}`.
+    pub fn encode(value: T) -> String {
+        serde_json::to_string(&JsonData { data: value }).unwrap_or_default()
+    }
+}
@@ -0,0 +1,17 @@
+//! RPC layer error codes, shared by all services.
+
+pub const ROBOT_OK: i32 = 0;
+pub const ROBOT_ERR_UNKNOWN: i32 = 3001;
+pub const ROBOT_ERR_CLIENT_SEND: i32 = 3102;
+pub const ROBOT_ERR_CLIENT_API_NOT_REG: i32 = 3103;
+pub const ROBOT_ERR_CLIENT_API_TIMEOUT: i32 = 3104;
+pub const ROBOT_ERR_CLIENT_API_NOT_MATCH: i32 = 3105;
+pub const ROBOT_ERR_CLIENT_API_DATA: i32 = 3106;
+pub const ROBOT_ERR_CLIENT_LEASE_INVALID: i32 = 3107;
+pub const ROBOT_ERR_SERVER_SEND: i32 = 3201;
+pub const ROBOT_ERR_SERVER_INTERNAL: i32 = 3202;
+pub const ROBOT_ERR_SERVER_API_NOT_IMPL: i32 = 3203;
+pub const ROBOT_ERR_SERVER_API_PARAMETER: i32 = 3204;
+pub const ROBOT_ERR_SERVER_LEASE_DENIED: i32 = 3205;
+pub const ROBOT_ERR_SERVER_LEASE_NOT_EXIST: i32 = 3206;
+pub const ROBOT_ERR_SERVER_LEASE_EXIST: i32 = 3207;
@@ -0,0 +1,4 @@
+//! Runtime safety checks for low-level control.
+pub mod monitors;
+
+pub use monitors::{BatteryView, Condition, DampingCommand, Fault, LowStateView, SafetyMonitor};
@@ -0,0 +1,616 @@
+//! Termination and fault conditions evaluated on robot state.
+//!
+//! The free functions mirror the checks in the C++ `g1/common/terminations.hpp`
+//! and work on both Go2 and HG (G1/H1) messages through [`LowStateView`] and
+//! [`BatteryView`]. [`SafetyMonitor`] evaluates a configurable set of
+//! [`Condition`]s on every state, reports which fired and why, and latches so
+//! a control loop can keep damping the motors until it is reset.
+
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::idl::{go2, hg};
+
+/// Motor read-outs and IMU data needed by the monitors.
+pub trait LowStateView {
+    /// IMU orientation as `[w, x, y, z]`.
+    fn quaternion(&self) -> [f32; 4];
+    /// IMU angular velocity, in rad/s.
+    fn gyroscope(&self) -> [f32; 3];
+    /// Number of motor slots in the message.
+    fn motor_count(&self) -> usize;
+    /// Joint velocity of motor `i`, in rad/s.
+    fn motor_dq(&self, i: usize) -> f32;
+    /// Winding temperature of motor `i`, in °C.
+    fn motor_winding_temperature(&self, i: usize) -> Option<f32>;
+    /// Casing temperature of motor `i`, in °C.
+    fn motor_casing_temperature(&self, i: usize) -> Option<f32>;
+    /// Battery state carried in the same message, if any. HG robots
+    /// publish it separately; pass it to [`SafetyMonitor::check_battery`].
+    fn battery(&self) -> Option<&dyn BatteryView> {
+        None
+    }
+}
+
+/// Battery read-outs needed by the monitors.
+pub trait BatteryView {
+    /// State of charge, in percent.
+    fn soc(&self) -> f32;
+}
+
+impl LowStateView for hg::LowState {
+    fn quaternion(&self) -> [f32; 4] {
+        self.imu_state.quaternion
+    }
+
+    fn gyroscope(&self) -> [f32; 3] {
+        self.imu_state.gyroscope
+    }
+
+    fn motor_count(&self) -> usize {
+        self.motor_state.len()
+    }
+
+    fn motor_dq(&self, i: usize) -> f32 {
+        self.motor_state[i].dq
+    }
+
+    fn motor_winding_temperature(&self, i: usize) -> Option<f32> {
+        Some(self.motor_state[i].temperature[1] as f32)
+    }
+
+    fn motor_casing_temperature(&self, i: usize) -> Option<f32> {
+        Some(self.motor_state[i].temperature[0] as f32)
+    }
+}
+
+/// Go2 motors report a single temperature, checked as the winding
+/// temperature; casing checks never fire.
+impl LowStateView for go2::LowState {
+    fn quaternion(&self) -> [f32; 4] {
+        self.imu_state.quaternion
+    }
+
+    fn gyroscope(&self) -> [f32; 3] {
+        self.imu_state.gyroscope
+    }
+
+    fn motor_count(&self) -> usize {
+        self.motor_state.len()
+    }
+
+    fn motor_dq(&self, i: usize) -> f32 {
+        self.motor_state[i].dq
+    }
+
+    fn motor_winding_temperature(&self, i: usize) -> Option<f32> {
+        Some(self.motor_state[i].temperature as f32)
+    }
+
+    fn motor_casing_temperature(&self, _i: usize) -> Option<f32> {
+        None
+    }
+
+    fn battery(&self) -> Option<&dyn BatteryView> {
+        Some(self)
+    }
+}
+
+impl BatteryView for hg::BmsState {
+    fn soc(&self) -> f32 {
+        self.soc as f32
+    }
+}
+
+impl BatteryView for go2::BmsState {
+    fn soc(&self) -> f32 {
+        self.soc as f32
+    }
+}
+
+/// Go2 carries its battery state inside `LowState`.
+impl BatteryView for go2::LowState {
+    fn soc(&self) -> f32 {
+        self.bms_state.soc as f32
+    }
+}
+
+/// Angle between the body z axis and the world z axis, in radians.
+pub fn tilt_angle(quaternion: [f32; 4]) -> f32 {
+    let [w, x, y, z] = quaternion;
+    let norm = w * w + x * x + y * y + z * z;
+    if norm <= 0.0 {
+        return 0.0;
+    }
+    // Gravity projected into the body frame is -R^T e_z, whose z component
+    // is -R[2][2].
+    let r22 = (w * w - x * x - y * y + z * z) / norm;
+    r22.clamp(-1.0, 1.0).acos()
+}
+
+/// Robot orientation is further than `limit_angle` (rad) from upright.
+pub fn bad_orientation(state: &impl LowStateView, limit_angle: f32) -> bool {
+    tilt_angle(state.quaternion()).abs() > limit_angle
+}
+
+/// Any joint velocity exceeds `limit_vel` (rad/s).
+pub fn joint_vel_out_of_limit(state: &impl LowStateView, limit_vel: f32) -> bool {
+    (0..state.motor_count()).any(|i| state.motor_dq(i).abs() > limit_vel)
+}
+
+/// Any IMU angular velocity component exceeds `limit_vel` (rad/s).
+pub fn ang_vel_out_of_limit(state: &impl LowStateView, limit_vel: f32) -> bool {
+    state.gyroscope().iter().any(|w| w.abs() > limit_vel)
+}
+
+/// Any motor winding temperature exceeds `limit_temp` (°C).
+pub fn motor_winding_overheat(state: &impl LowStateView, limit_temp: f32) -> bool {
+    (0..state.motor_count()).any(|i| state.motor_winding_temperature(i).is_some_and(|t| t > limit_temp))
+}
+
+/// Any motor casing temperature exceeds `limit_temp` (°C).
+pub fn motor_casing_overheat(state: &impl LowStateView, limit_temp: f32) -> bool {
+    (0..state.motor_count()).any(|i| state.motor_casing_temperature(i).is_some_and(|t| t > limit_temp))
+}
+
+/// State of charge is below `limit_soc` (%).
+pub fn low_battery(bms: &(impl BatteryView + ?Sized), limit_soc: f32) -> bool {
+    bms.soc() < limit_soc
+}
+
+/// No state has arrived for longer than `timeout`.
+///
+/// With a wired connection a loose cable interrupts the state stream; a
+/// controller that keeps running on stale state sends step commands to the
+/// motors and moves them violently.
+pub fn lost_connection(last_state: Instant, now: Instant, timeout: Duration) -> bool {
+    now.saturating_duration_since(last_state) > timeout
+}
+
+/// A fault condition and its limit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Condition {
+    /// Tilt from upright above `limit_angle` (rad).
+    BadOrientation { limit_angle: f32 },
+    /// Joint velocity above `limit_vel` (rad/s).
+    JointVelOutOfLimit { limit_vel: f32 },
+    /// IMU angular velocity above `limit_vel` (rad/s).
+    AngVelOutOfLimit { limit_vel: f32 },
+    /// Motor winding temperature above `limit_temp` (°C).
+    MotorWindingOverheat { limit_temp: f32 },
+    /// Motor casing temperature above `limit_temp` (°C).
+    MotorCasingOverheat { limit_temp: f32 },
+    /// Battery state of charge below `limit_soc` (%).
+    LowBattery { limit_soc: f32 },
+    /// No state for longer than `timeout`.
+    LostConnection { timeout: Duration },
+}
+
+impl Condition {
+    /// All conditions with the limits used by the C++ SDK.
+    pub const DEFAULTS: [Condition; 7] = [
+        Condition::BadOrientation { limit_angle: 1.0 },
+        Condition::JointVelOutOfLimit { limit_vel: 10.0 },
+        Condition::AngVelOutOfLimit { limit_vel: 6.0 },
+        Condition::MotorWindingOverheat { limit_temp: 120.0 },
+        Condition::MotorCasingOverheat { limit_temp: 85.0 },
+        Condition::LowBattery { limit_soc: 20.0 },
+        Condition::LostConnection { timeout: Duration::from_millis(1000) },
+    ];
+
+    /// Short name, matching the C++ function names.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Condition::BadOrientation { .. } => "bad_orientation",
+            Condition::JointVelOutOfLimit { .. } => "joint_vel_out_of_limit",
+            Condition::AngVelOutOfLimit { .. } => "ang_vel_out_of_limit",
+            Condition::MotorWindingOverheat { .. } => "motor_winding_overheat",
+            Condition::MotorCasingOverheat { .. } => "motor_casing_overheat",
+            Condition::LowBattery { .. } => "low_battery",
+            Condition::LostConnection { .. } => "lost_connection",
+        }
+    }
+
+    /// Evaluate a state condition. Battery and connection conditions are
+    /// not evaluated here and return `None`.
+    pub fn check_low_state(&self, state: &impl LowStateView) -> Option<Fault> {
+        let motors = 0..state.motor_count();
+        let (value, motor) = match *self {
+            Condition::BadOrientation { limit_angle } => {
+                let angle = tilt_angle(state.quaternion());
+                (angle > limit_angle).then_some((angle, None))?
+            }
+            Condition::JointVelOutOfLimit { limit_vel } => motors
+                .map(|i| (state.motor_dq(i).abs(), Some(i)))
+                .find(|(v, _)| *v > limit_vel)?,
+            Condition::AngVelOutOfLimit { limit_vel } => state
+                .gyroscope()
+                .iter()
+                .map(|w| (w.abs(), None))
+                .find(|(v, _)| *v > limit_vel)?,
+            Condition::MotorWindingOverheat { limit_temp } => motors
+                .filter_map(|i| Some((state.motor_winding_temperature(i)?, Some(i))))
+                .find(|(t, _)| *t > limit_temp)?,
+            Condition::MotorCasingOverheat { limit_temp } => motors
+                .filter_map(|i| Some((state.motor_casing_temperature(i)?, Some(i))))
+                .find(|(t, _)| *t > limit_temp)?,
+            Condition::LowBattery { .. } | Condition::LostConnection { .. } => return None,
+        };
+        Some(Fault { condition: *self, value, motor })
+    }
+
+    /// Evaluate a battery condition. Other conditions return `None`.
+    pub fn check_battery(&self, bms: &(impl BatteryView + ?Sized)) -> Option<Fault> {
+        match *self {
+            Condition::LowBattery { limit_soc } if low_battery(bms, limit_soc) => Some(Fault {
+                condition: *self,
+                value: bms.soc(),
+                motor: None,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Evaluate a connection condition. Other conditions return `None`.
+    pub fn check_connection(&self, last_state: Instant, now: Instant) -> Option<Fault> {
+        match *self {
+            Condition::LostConnection { timeout } if lost_connection(last_state, now, timeout) => {
+                Some(Fault {
+                    condition: *self,
+                    value: now.saturating_duration_since(last_state).as_secs_f32(),
+                    motor: None,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A condition that fired, with the offending value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fault {
+    pub condition: Condition,
+    /// Measured value, in the unit of the condition's limit (seconds for
+    /// `LostConnection`).
+    pub value: f32,
+    /// Motor index, for per-motor conditions.
+    pub motor: Option<usize>,
+}
+
+impl fmt::Display for Fault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let motor = match self.motor {
+            Some(i) => format!(" on motor {i}"),
+            None => String::new(),
+        };
+        match self.condition {
+            Condition::BadOrientation { limit_angle } => {
+                write!(f, "tilt {:.3} rad exceeds {limit_angle} rad", self.value)
+            }
+            Condition::JointVelOutOfLimit { limit_vel } => {
+                write!(f, "joint velocity {:.3} rad/s{motor} exceeds {limit_vel} rad/s", self.value)
+            }
+            Condition::AngVelOutOfLimit { limit_vel } => {
+                write!(f, "angular velocity {:.3} rad/s exceeds {limit_vel} rad/s", self.value)
+            }
+            Condition::MotorWindingOverheat { limit_temp } => {
+                write!(f, "winding temperature {}°C{motor} exceeds {limit_temp}°C", self.value)
+            }
+            Condition::MotorCasingOverheat { limit_temp } => {
+                write!(f, "casing temperature {}°C{motor} exceeds {limit_temp}°C", self.value)
+            }
+            Condition::LowBattery { limit_soc } => {
+                write!(f, "battery {}% is below {limit_soc}%", self.value)
+            }
+            Condition::LostConnection { timeout } => {
+                write!(f, "no state for {:.3} s (timeout {:.3} s)", self.value, timeout.as_secs_f32())
+            }
+        }
+    }
+}
+
+/// Motor commands that can be put into damping.
+pub trait DampingCommand {
+    /// Command every motor to zero stiffness, zero torque and damping `kd`.
+    fn set_damping(&mut self, kd: f32);
+}
+
+impl DampingCommand for go2::LowCmd {
+    fn set_damping(&mut self, kd: f32) {
+        for motor in self.motor_cmd.iter_mut() {
+            motor.q = 0.0;
+            motor.dq = 0.0;
+            motor.tau = 0.0;
+            motor.kp = 0.0;
+            motor.kd = kd;
+        }
+    }
+}
+
+impl DampingCommand for hg::LowCmd {
+    fn set_damping(&mut self, kd: f32) {
+        for motor in self.motor_cmd.iter_mut() {
+            motor.q = 0.0;
+            motor.dq = 0.0;
+            motor.tau = 0.0;
+            motor.kp = 0.0;
+            motor.kd = kd;
+        }
+    }
+}
+
+/// Damping gain applied by [`SafetyMonitor::guard`] by default.
+pub const DEFAULT_DAMPING_KD: f32 = 1.0;
+
+/// Evaluates a set of conditions on every state and latches on the first
+/// fault.
+#[derive(Debug, Clone)]
+pub struct SafetyMonitor {
+    conditions: Vec<Condition>,
+    damping_kd: f32,
+    last_state: Option<Instant>,
+    faults: Vec<Fault>,
+}
+
+impl SafetyMonitor {
+    /// Monitor the given conditions.
+    pub fn new(conditions: impl IntoIterator<Item = Condition>) -> Self {
+        Self {
+            conditions: conditions.into_iter().collect(),
+            damping_kd: DEFAULT_DAMPING_KD,
+            last_state: None,
+            faults: Vec::new(),
+        }
+    }
+
+    /// Set the damping gain used by [`SafetyMonitor::guard`].
+    pub fn with_damping_kd(mut self, kd: f32) -> Self {
+        self.damping_kd = kd;
+        self
+    }
+
+    /// Monitored conditions.
+    pub fn conditions(&self) -> &[Condition] {
+        &self.conditions
+    }
+
+    /// First fault of each condition recorded since the last
+    /// [`SafetyMonitor::reset`].
+    pub fn faults(&self) -> &[Fault] {
+        &self.faults
+    }
+
+    /// Whether any fault has fired since the last reset.
+    pub fn tripped(&self) -> bool {
+        !self.faults.is_empty()
+    }
+
+    /// Clear recorded faults and the connection timer.
+    pub fn reset(&mut self) {
+        self.faults.clear();
+        self.last_state = None;
+    }
+
+    fn record(&mut self, fired: Vec<Fault>) -> Vec<Fault> {
+        for fault in &fired {
+            if self.faults.iter().all(|f| f.condition != fault.condition) {
+                log::warn!("SafetyMonitor: {} fired: {}", fault.condition.name(), fault);
+                self.faults.push(*fault);
+            }
+        }
+        fired
+    }
+
+    /// Evaluate the state conditions on a new `LowState` and restart the
+    /// connection timer. Returns the faults that fired on this state.
+    pub fn check_low_state(&mut self, state: &impl LowStateView) -> Vec<Fault> {
+        self.last_state = Some(Instant::now());
+        let fired = self
+            .conditions
+            .iter()
+            .filter_map(|c| c.check_low_state(state))
+            .collect();
+        self.record(fired)
+    }
+
+    /// Evaluate the battery conditions. Returns the faults that fired.
+    pub fn check_battery(&mut self, bms: &(impl BatteryView + ?Sized)) -> Vec<Fault> {
+        let fired = self
+            .conditions
+            .iter()
+            .filter_map(|c| c.check_battery(bms))
+            .collect();
+        self.record(fired)
+    }
+
+    /// Evaluate the connection conditions against the time of the last
+    /// state passed to [`SafetyMonitor::check_low_state`]. Nothing fires
+    /// before the first state arrives.
+    pub fn check_connection(&mut self, now: Instant) -> Vec<Fault> {
+        let Some(last_state) = self.last_state else {
+            return Vec::new();
+        };
+        let fired = self
+            .conditions
+            .iter()
+            .filter_map(|c| c.check_connection(last_state, now))
+            .collect();
+        self.record(fired)
+    }
+
+    /// Control-loop hook: check the connection and, if a new state is
+    /// given, the state conditions and the battery it carries; then, if the
+    /// monitor has tripped, overwrite `cmd` with damping. Returns whether
+    /// `cmd` was damped.
+    pub fn guard(&mut self, state: Option<&impl LowStateView>, cmd: &mut impl DampingCommand) -> bool {
+        if let Some(state) = state {
+            self.check_low_state(state);
+            if let Some(bms) = state.battery() {
+                self.check_battery(bms);
+            }
+        }
+        self.check_connection(Instant::now());
+        if self.tripped() {
+            cmd.set_damping(self.damping_kd);
+        }
+        self.tripped()
+    }
+}
+
+impl Default for SafetyMonitor {
+    /// Monitor every condition with the C++ SDK limits.
+    fn default() -> Self {
+        Self::new(Condition::DEFAULTS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn upright_go2(soc: u8) -> go2::LowState {
+        let mut state = go2::LowState::default();
+        state.imu_state.quaternion = [1.0, 0.0, 0.0, 0.0];
+        state.bms_state.soc = soc;
+        state
+    }
+
+    #[test]
+    fn guard_damps_on_low_go2_battery() {
+        let mut monitor = SafetyMonitor::default();
+        let mut cmd = go2::LowCmd::default();
+        assert!(!monitor.guard(Some(&upright_go2(80)), &mut cmd));
+
+        assert!(monitor.guard(Some(&upright_go2(10)), &mut cmd));
+        assert_eq!(monitor.faults()[0].condition.name(), "low_battery");
+    }
+
+    #[test]
+    fn hg_state_carries_no_battery() {
+        assert!(hg::LowState::default().battery().is_none());
+    }
+
+    /// `acos(-projected_gravity_b.z)` as computed in the C++ terminations,
+    /// with the gravity direction rotated by the inverse of `q`.
+    fn cpp_tilt([w, x, y, z]: [f32; 4]) -> f32 {
+        let v = [0.0, 0.0, -1.0f32];
+        let u = [x, y, z];
+        let cross = [u[1] * v[2] - u[2] * v[1], u[2] * v[0] - u[0] * v[2], u[0] * v[1] - u[1] * v[0]];
+        let dot = u[0] * v[0] + u[1] * v[1] + u[2] * v[2];
+        let gz = v[2] * (2.0 * w * w - 1.0) - cross[2] * w * 2.0 + u[2] * dot * 2.0;
+        (-gz).clamp(-1.0, 1.0).acos()
+    }
+
+    fn roll(angle: f32) -> [f32; 4] {
+        [(angle / 2.0).cos(), (angle / 2.0).sin(), 0.0, 0.0]
+    }
+
+    #[test]
+    fn tilt_angle_matches_the_cpp_projection() {
+        use std::f32::consts::{FRAC_PI_2, PI};
+
+        assert!(tilt_angle([1.0, 0.0, 0.0, 0.0]).abs() < 1e-6);
+        assert!((tilt_angle(roll(FRAC_PI_2)) - FRAC_PI_2).abs() < 1e-3);
+        assert!((tilt_angle([0.0, 1.0, 0.0, 0.0]) - PI).abs() < 1e-3);
+        // Yaw alone does not tilt the body.
+        assert!(tilt_angle([0.6, 0.0, 0.0, 0.8]).abs() < 1e-3);
+
+        let half = (0.5f32).sqrt();
+        for q in [roll(0.3), roll(-2.0), [0.5, 0.5, 0.5, 0.5], [half, 0.0, half, 0.0], [0.9, 0.1, -0.3, 0.2]] {
+            let norm = q.iter().map(|c| c * c).sum::<f32>().sqrt();
+            let unit = q.map(|c| c / norm);
+            assert!((tilt_angle(q) - cpp_tilt(unit)).abs() < 1e-3, "{q:?}");
+        }
+    }
+
+    #[test]
+    fn conditions_report_value_and_motor() {
+        let mut state = upright_go2(80);
+        state.imu_state.quaternion = roll(1.5);
+        state.imu_state.gyroscope = [0.0, -7.0, 1.0];
+        state.motor_state[4].dq = -12.0;
+        state.motor_state[7].temperature = 130;
+
+        let fault = Condition::BadOrientation { limit_angle: 1.0 }.check_low_state(&state).unwrap();
+        assert!((fault.value - 1.5).abs() < 1e-3);
+        assert_eq!(fault.motor, None);
+
+        let fault = Condition::JointVelOutOfLimit { limit_vel: 10.0 }.check_low_state(&state).unwrap();
+        assert_eq!((fault.value, fault.motor), (12.0, Some(4)));
+
+        let fault = Condition::AngVelOutOfLimit { limit_vel: 6.0 }.check_low_state(&state).unwrap();
+        assert_eq!((fault.value, fault.motor), (7.0, None));
+
+        let fault = Condition::MotorWindingOverheat { limit_temp: 120.0 }.check_low_state(&state).unwrap();
+        assert_eq!((fault.value, fault.motor), (130.0, Some(7)));
+
+        // Go2 has no casing temperature, and battery and connection are
+        // checked elsewhere.
+        assert!(Condition::MotorCasingOverheat { limit_temp: 0.0 }.check_low_state(&state).is_none());
+        assert!(Condition::LowBattery { limit_soc: 100.0 }.check_low_state(&state).is_none());
+
+        let mut hg_state = hg::LowState::default();
+        hg_state.imu_state.quaternion = [1.0, 0.0, 0.0, 0.0];
+        hg_state.motor_state[2].temperature = [90, 60];
+        hg_state.motor_state[5].temperature = [40, 125];
+        let fault = Condition::MotorCasingOverheat { limit_temp: 85.0 }.check_low_state(&hg_state).unwrap();
+        assert_eq!((fault.value, fault.motor), (90.0, Some(2)));
+        let fault = Condition::MotorWindingOverheat { limit_temp: 120.0 }.check_low_state(&hg_state).unwrap();
+        assert_eq!((fault.value, fault.motor), (125.0, Some(5)));
+
+        let fault = Condition::LowBattery { limit_soc: 20.0 }.check_battery(&upright_go2(15)).unwrap();
+        assert_eq!((fault.value, fault.motor), (15.0, None));
+
+        assert!(Condition::DEFAULTS.iter().all(|c| c.check_low_state(&upright_go2(80)).is_none()));
+    }
+
+    #[test]
+    fn connection_fires_only_after_timeout() {
+        let timeout = Duration::from_millis(1000);
+        let condition = Condition::LostConnection { timeout };
+        let last = Instant::now();
+        assert!(condition.check_connection(last, last + timeout).is_none());
+        let fault = condition.check_connection(last, last + Duration::from_millis(1500)).unwrap();
+        assert_eq!((fault.value, fault.motor), (1.5, None));
+
+        let mut monitor = SafetyMonitor::new([condition]);
+        // No state yet: a missing stream at startup is not a lost connection.
+        assert!(monitor.check_connection(Instant::now() + 10 * timeout).is_empty());
+
+        monitor.check_low_state(&upright_go2(80));
+        let now = Instant::now();
+        assert!(monitor.check_connection(now + Duration::from_millis(900)).is_empty());
+        assert!(!monitor.tripped());
+        assert_eq!(monitor.check_connection(now + Duration::from_millis(1100)).len(), 1);
+        assert!(monitor.tripped());
+
+        monitor.reset();
+        assert!(monitor.check_connection(now + 10 * timeout).is_empty());
+    }
+
+    #[test]
+    fn record_latches_the_first_fault_per_condition() {
+        let mut monitor = SafetyMonitor::default();
+        let mut state = upright_go2(80);
+        state.motor_state[1].dq = 11.0;
+        assert_eq!(monitor.check_low_state(&state).len(), 1);
+
+        // A worse reading is returned but does not replace the first one.
+        state.motor_state[1].dq = 20.0;
+        state.imu_state.gyroscope = [8.0, 0.0, 0.0];
+        let fired = monitor.check_low_state(&state);
+        assert_eq!(fired.len(), 2);
+        assert_eq!(fired[0].value, 20.0);
+
+        let faults = monitor.faults();
+        assert_eq!(faults.len(), 2);
+        assert_eq!((faults[0].condition.name(), faults[0].value), ("joint_vel_out_of_limit", 11.0));
+        assert_eq!(faults[1].condition.name(), "ang_vel_out_of_limit");
+
+        // The monitor stays tripped on a clean state until reset.
+        assert!(monitor.check_low_state(&upright_go2(80)).is_empty());
+        assert!(monitor.tripped());
+        monitor.reset();
+        assert!(!monitor.tripped());
+    }
+}